@@ -1,13 +1,14 @@
 //! This module provides functionalities for
 //! manipulating the actual Document Object Model in the browser
 
-pub use cmd::Cmd;
+pub use cmd::{Cmd, CmdHandle};
 pub use component::Component;
 pub use effects::Effects;
 
 mod cmd;
 mod component;
 mod effects;
+pub mod router;
 
 use cfg_if::cfg_if;
 
@@ -16,9 +17,13 @@ cfg_if! {if #[cfg(feature = "with-dom")] {
     pub use component::{stateful_component, StatefulComponent, StatefulModel, StatelessModel};
     pub use component::component;
     pub use dom_patch::{DomPatch, PatchVariant};
+    pub use executor::Executor;
+    pub use middleware::{Intercept, Middleware};
     pub use dom_attr::{DomAttr, DomAttrValue, GroupedDomAttrValues};
     pub use http::Http;
     pub use program::{MountAction, MountTarget, Program, MountProcedure};
+    pub use recorder::{Record, Recorder};
+    pub use scheduler::{Schedule, Scheduler, Task};
     pub use util::{
         document, history, now, performance,
         spawn_local, window, inject_style,
@@ -27,6 +32,7 @@ cfg_if! {if #[cfg(feature = "with-dom")] {
     pub use ric::{request_idle_callback, IdleCallbackHandle, IdleDeadline};
     pub use timeout::{delay, request_timeout_callback, TimeoutCallbackHandle};
     pub use dispatch::Dispatch;
+    pub use subscription::Subscription;
     use crate::dom::events::MountEvent;
     pub use window::Window;
     pub use dom_node::DomNode;
@@ -39,8 +45,13 @@ cfg_if! {if #[cfg(feature = "with-dom")] {
     mod dom_patch;
     mod dom_attr;
     pub mod events;
+    mod executor;
     mod http;
+    mod middleware;
     mod program;
+    mod recorder;
+    mod scheduler;
+    mod subscription;
     pub mod util;
     mod raf;
     mod ric;
@@ -1,4 +1,4 @@
-use crate::dom::Cmd;
+use crate::dom::{Cmd, Subscription};
 use crate::vdom::Node;
 pub use skip_diff::{skip_if, SkipDiff, SkipPath};
 
@@ -26,6 +26,13 @@ pub trait Application: Sized + 'static {
     /// Returns a node on how the component is presented.
     fn view(&self) -> Node<Self::MSG>;
 
+    /// The global events and intervals the application listens to, such as the window resize.
+    /// This is evaluated again after each update, the listeners of the subscriptions which are
+    /// no longer returned are removed and the listeners of the new ones are added.
+    fn subscriptions(&self) -> Subscription<Self::MSG> {
+        Subscription::none()
+    }
+
     /// The css style for the application, will be mounted automatically by the program
     fn stylesheet() -> Vec<String> {
         vec![]
@@ -1,3 +1,4 @@
+use crate::vdom::Node;
 use crate::vdom::TreePath;
 
 /// specifies how attributes will be skipped
@@ -87,6 +88,36 @@ impl SkipDiff {
             },
         }
     }
+
+    /// the skip diff to use when patching the `template` into the `view`.
+    /// The template only has the literal attributes, so the attribute indices no longer align
+    /// and are not skipped. Where a block in the view expanded into a different number of nodes
+    /// than the template, nothing is skipped in that element and its children.
+    pub(crate) fn for_template<MSG>(&self, template: &Node<MSG>, view: &Node<MSG>) -> Self {
+        if self.shall_skip_node() {
+            return self.clone();
+        }
+        let template_children = template.children();
+        let view_children = view.children();
+        let is_aligned = template_children.len() == view_children.len()
+            && self.children.len() == view_children.len();
+        if !is_aligned {
+            return Self::block();
+        }
+        Self {
+            skip_attrs: if self.shall_skip_attributes() {
+                SkipAttrs::All
+            } else {
+                SkipAttrs::none()
+            },
+            children: self
+                .children
+                .iter()
+                .zip(template_children.iter().zip(view_children.iter()))
+                .map(|(skip_diff, (template, view))| skip_diff.for_template(template, view))
+                .collect(),
+        }
+    }
 }
 
 /// skip diffing the node is the val is true
@@ -1,11 +1,16 @@
+#[cfg(feature = "with-dom")]
+use crate::dom::dom_node::intern;
 use crate::dom::Effects;
-use futures::channel::mpsc;
 use futures::channel::mpsc::UnboundedReceiver;
-use futures::StreamExt;
+use futures::channel::oneshot;
+use futures::future::{self, AbortHandle, AbortRegistration, Abortable};
+use futures::{stream, FutureExt, Stream, StreamExt};
+use std::cell::RefCell;
 use std::future::Future;
 use std::pin::Pin;
+use std::rc::Rc;
 #[cfg(feature = "with-dom")]
-use wasm_bindgen::closure::Closure;
+use wasm_bindgen::{closure::Closure, JsCast};
 
 /// Cmd is a way to tell the Runtime that something needs to be executed
 pub struct Cmd<MSG> {
@@ -13,11 +18,47 @@ pub struct Cmd<MSG> {
     pub(crate) commands: Vec<Command<MSG>>,
 }
 
+/// A handle to cancel the Cmd it is created with, see [`Cmd::cancellable`].
+/// The handle can be cloned and stored in the state of the application
+#[derive(Clone, Default)]
+pub struct CmdHandle {
+    state: Rc<RefCell<HandleState>>,
+}
+
+#[derive(Default)]
+struct HandleState {
+    cancelled: bool,
+    abort_handles: Vec<AbortHandle>,
+    teardowns: Vec<Teardown>,
+}
+
+impl CmdHandle {
+    /// cancel the Cmd, cancelling it again has no effect
+    pub fn cancel(&self) {
+        let mut state = self.state.borrow_mut();
+        if state.cancelled {
+            return;
+        }
+        state.cancelled = true;
+        // the event listeners are removed first, so the closures are not called once dropped
+        for teardown in state.teardowns.drain(..) {
+            run_teardown(&teardown);
+        }
+        for abort_handle in state.abort_handles.drain(..) {
+            abort_handle.abort();
+        }
+    }
+
+    /// returns true if the Cmd is already cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.state.borrow().cancelled
+    }
+}
+
 /// encapsulate anything a component can do
 pub enum Command<MSG> {
     /// A task with one single resulting MSG
     Action(Action<MSG>),
-    #[cfg(feature = "with-dom")]
     /// A task with recurring resulting MSG
     Sub(Sub<MSG>),
 }
@@ -43,7 +84,19 @@ where
             commands: vec![Command::single(f)],
         }
     }
+    /// Creates a Cmd which calls the function when it is executed, without emitting a msg
+    #[cfg(feature = "with-dom")]
+    pub(crate) fn from_effect(effect: impl FnOnce() + 'static) -> Self {
+        Self {
+            commands: vec![Command::Action(Action::from_task(async move {
+                effect();
+                None
+            }))],
+        }
+    }
+
     /// Creates a Cmd which will be polled multiple times
+    #[cfg(feature = "with-dom")]
     pub fn recurring(
         rx: UnboundedReceiver<MSG>,
         event_closure: Closure<dyn FnMut(web_sys::Event)>,
@@ -53,6 +106,106 @@ where
         }
     }
 
+    /// Creates a recurring Cmd which emits the msgs of the stream, such as the frames of a
+    /// websocket or the msgs of a channel fed by a js library.
+    /// The stream is dropped when it ends or when the Cmd is cancelled
+    ///
+    /// # Example
+    /// ```rust
+    /// use futures::stream;
+    /// use sauron::Cmd;
+    ///
+    /// let cmd: Cmd<u32> = Cmd::from_stream(stream::iter([1, 2, 3]));
+    /// ```
+    pub fn from_stream<S>(stream: S) -> Self
+    where
+        S: Stream<Item = MSG> + 'static,
+    {
+        Self {
+            commands: vec![Command::Sub(Sub {
+                receiver: Box::pin(stream),
+                listening: Listening::default(),
+            })],
+        }
+    }
+
+    /// add the closure as an event listener of the target and create a recurring Cmd
+    /// which emits the msgs from `rx`, the event listener is removed when the Cmd is cancelled
+    #[cfg(feature = "with-dom")]
+    pub(crate) fn recurring_listener(
+        target: &web_sys::EventTarget,
+        event_name: &'static str,
+        rx: UnboundedReceiver<MSG>,
+        event_closure: Closure<dyn FnMut(web_sys::Event)>,
+    ) -> Self {
+        let function: js_sys::Function = event_closure
+            .as_ref()
+            .unchecked_ref::<js_sys::Function>()
+            .clone();
+        target
+            .add_event_listener_with_callback(intern(event_name), &function)
+            .expect("add event callback");
+        let target = target.clone();
+        let mut cmd = Self::recurring(rx, event_closure);
+        cmd.set_teardown(move || {
+            target
+                .remove_event_listener_with_callback(intern(event_name), &function)
+                .expect("remove event callback");
+        });
+        cmd
+    }
+
+    /// set the function which removes the event listener or clears the interval of the closure
+    /// of this recurring Cmd, which is called when the Cmd is cancelled
+    #[cfg(feature = "with-dom")]
+    pub(crate) fn set_teardown(&mut self, teardown: impl FnOnce() + 'static) {
+        if let Some(Command::Sub(sub)) = self.commands.first_mut() {
+            *sub.listening.teardown.borrow_mut() = Some(Box::new(teardown));
+        }
+    }
+
+    /// returns this Cmd along with a handle which can cancel it,
+    /// such as when the result of a fetch is no longer needed or to stop listening to an event.
+    /// Cancelling a Cmd drops its future, removes its event listener or clears its interval,
+    /// the msgs which are not emitted yet are discarded
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// Msg::StartDrag => {
+    ///     let (cmd, handle) = Window::on_mousemove(Msg::Drag).cancellable();
+    ///     self.drag_handle = Some(handle);
+    ///     cmd
+    /// }
+    /// Msg::EndDrag => {
+    ///     if let Some(handle) = self.drag_handle.take() {
+    ///         handle.cancel();
+    ///     }
+    ///     Cmd::none()
+    /// }
+    /// ```
+    pub fn cancellable(self) -> (Self, CmdHandle) {
+        let handle = CmdHandle::default();
+        let commands = self
+            .commands
+            .into_iter()
+            .map(|command| {
+                let (abort_handle, registration) = AbortHandle::new_pair();
+                let mut state = handle.state.borrow_mut();
+                state.abort_handles.push(abort_handle);
+                match command {
+                    Command::Action(action) => Command::Action(action.abortable(registration)),
+                    Command::Sub(sub) => {
+                        // shared with the Cmd, so it is called once whether the Cmd is
+                        // cancelled or dropped first
+                        state.teardowns.push(Rc::clone(&sub.listening.teardown));
+                        Command::Sub(sub.abortable(registration))
+                    }
+                }
+            })
+            .collect();
+        (Self { commands }, handle)
+    }
+
     /// map the msg of this Cmd such that Cmd<MSG> becomes Cmd<MSG2>.
     pub fn map_msg<F, MSG2>(self, f: F) -> Cmd<MSG2>
     where
@@ -68,6 +221,118 @@ where
         }
     }
 
+    /// run a follow-up future with each msg of this Cmd, the msg of the follow-up is emitted
+    /// instead
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// Cmd::new(fetch_user(id)).and_then(|user| fetch_posts(user))
+    /// ```
+    pub fn and_then<F, FUT, MSG2>(self, f: F) -> Cmd<MSG2>
+    where
+        F: Fn(MSG) -> FUT + Clone + 'static,
+        FUT: Future<Output = MSG2> + 'static,
+        MSG2: 'static,
+    {
+        Cmd {
+            commands: self
+                .commands
+                .into_iter()
+                .map(|command| command.and_then(f.clone()))
+                .collect(),
+        }
+    }
+
+    /// run the tasks of the Cmds one after the other in order,
+    /// a task is started only after the msg of the previous task is emitted.
+    /// The recurring Cmds are not sequenced, they are started right away
+    pub fn sequence(tasks: impl IntoIterator<Item = Self>) -> Self {
+        let mut previous: Option<oneshot::Receiver<()>> = None;
+        let commands = tasks
+            .into_iter()
+            .flat_map(|task| task.commands)
+            .map(|command| match command {
+                Command::Action(action) => {
+                    let (done_tx, done_rx) = oneshot::channel();
+                    let previous = previous.replace(done_rx);
+                    let task = action.into_task();
+                    Command::Action(Action::from_task(async move {
+                        // also proceed when the previous task is cancelled
+                        if let Some(previous) = previous {
+                            let _ = previous.await;
+                        }
+                        let msg = task.await;
+                        let _ = done_tx.send(());
+                        msg
+                    }))
+                }
+                sub => sub,
+            })
+            .collect();
+        Self { commands }
+    }
+
+    /// run the tasks of the Cmds at the same time, only the first emitted msg is used
+    /// and the rest of the tasks are dropped.
+    /// The recurring Cmds takes part in the race with their first msg
+    pub fn race(tasks: impl IntoIterator<Item = Self>) -> Self {
+        let mut tasks: Vec<_> = tasks
+            .into_iter()
+            .flat_map(|task| task.commands)
+            .map(Command::into_first)
+            .collect();
+        if tasks.is_empty() {
+            return Self::none();
+        }
+        Self {
+            commands: vec![Command::Action(Action::from_task(async move {
+                // the cancelled tasks do not win the race
+                while !tasks.is_empty() {
+                    let (msg, _index, rest) = future::select_all(tasks).await;
+                    if msg.is_some() {
+                        return msg;
+                    }
+                    tasks = rest;
+                }
+                None
+            }))],
+        }
+    }
+
+    /// emit the msg of `fallback` instead, for each task of this Cmd which does not complete
+    /// within `timeout_ms`
+    ///
+    /// # Example
+    /// ```rust,ignore
+    /// Cmd::new(fetch_data()).timeout(5_000, || Msg::Error("took too long".to_string()))
+    /// ```
+    #[cfg(feature = "with-dom")]
+    pub fn timeout<F>(self, timeout_ms: i32, fallback: F) -> Self
+    where
+        F: Fn() -> MSG + 'static,
+    {
+        let fallback = Rc::new(fallback);
+        let commands = self
+            .commands
+            .into_iter()
+            .map(|command| match command {
+                Command::Action(action) => {
+                    let task = action.into_task();
+                    let fallback = Rc::clone(&fallback);
+                    Command::Action(Action::from_task(async move {
+                        let delay = Box::pin(crate::dom::delay(timeout_ms));
+                        match future::select(task, delay).await {
+                            future::Either::Left((msg, _delay)) => msg,
+                            future::Either::Right(((), _task)) => Some(fallback()),
+                        }
+                    }))
+                }
+                sub => sub,
+            })
+            .collect();
+        Self { commands }
+    }
+
     /// batch together multiple Cmd into one task
     pub fn batch(tasks: impl IntoIterator<Item = Self>) -> Self {
         let mut commands = vec![];
@@ -116,8 +381,11 @@ where
         event_closure: Closure<dyn FnMut(web_sys::Event)>,
     ) -> Self {
         Self::Sub(Sub {
-            receiver: rx,
-            event_closure,
+            receiver: Box::pin(rx),
+            listening: Listening {
+                _event_closure: Some(event_closure),
+                teardown: Teardown::default(),
+            },
         })
     }
 
@@ -129,28 +397,53 @@ where
     {
         match self {
             Self::Action(task) => Command::Action(task.map_msg(f)),
-            #[cfg(feature = "with-dom")]
             Self::Sub(task) => Command::Sub(task.map_msg(f)),
         }
     }
 
-    /// return the next value
-    pub async fn next(&mut self) -> Option<MSG> {
+    /// run a follow-up future with each msg of this task
+    fn and_then<F, FUT, MSG2>(self, f: F) -> Command<MSG2>
+    where
+        F: Fn(MSG) -> FUT + 'static,
+        FUT: Future<Output = MSG2> + 'static,
+        MSG2: 'static,
+    {
         match self {
-            Self::Action(task) => task.next().await,
-            #[cfg(feature = "with-dom")]
-            Self::Sub(task) => task.next().await,
+            Self::Action(task) => Command::Action(task.and_then(f)),
+            Self::Sub(task) => Command::Sub(task.and_then(f)),
+        }
+    }
+
+    /// the future of the first msg of this task
+    fn into_first(self) -> Pin<Box<dyn Future<Output = Option<MSG>>>> {
+        match self {
+            Self::Action(task) => task.into_task(),
+            Self::Sub(task) => task.into_first(),
+        }
+    }
+
+    /// the msgs of this task as a stream, which ends when the task is done
+    pub(crate) fn into_stream(self) -> Pin<Box<dyn Stream<Item = MSG>>> {
+        match self {
+            Self::Action(task) => {
+                Box::pin(task.into_task().into_stream().filter_map(future::ready))
+            }
+            Self::Sub(Sub {
+                mut receiver,
+                listening,
+            }) => Box::pin(stream::poll_fn(move |cx| {
+                // the closure of the recurring task is kept alive along with the stream
+                let _ = &listening;
+                receiver.poll_next_unpin(cx)
+            })),
         }
     }
 }
 
 /// Action is used to do asynchronous operations
 pub struct Action<MSG> {
-    task: Pin<Box<dyn Future<Output = MSG>>>,
-    /// a marker to indicate if the value of the future is awaited.
-    /// any attempt to await it again will error,
-    /// saying that the async function is resumed after completion.
-    done: bool,
+    /// None when the task is cancelled
+    task: Pin<Box<dyn Future<Output = Option<MSG>>>>,
 }
 
 impl<MSG> Action<MSG>
@@ -163,8 +456,28 @@ where
         F: Future<Output = MSG> + 'static,
     {
         Self {
-            task: Box::pin(f),
-            done: false,
+            task: Box::pin(f.map(Some)),
+        }
+    }
+
+    fn from_task<F>(task: F) -> Self
+    where
+        F: Future<Output = Option<MSG>> + 'static,
+    {
+        Self {
+            task: Box::pin(task),
+        }
+    }
+
+    /// the future of this task, which resolves to None if it is cancelled
+    fn into_task(self) -> Pin<Box<dyn Future<Output = Option<MSG>>>> {
+        self.task
+    }
+
+    /// the task is dropped when aborted
+    fn abortable(self, registration: AbortRegistration) -> Self {
+        Self {
+            task: Box::pin(Abortable::new(self.task, registration).map(|msg| msg.ok().flatten())),
         }
     }
 
@@ -174,24 +487,25 @@ where
         F: Fn(MSG) -> MSG2 + 'static,
         MSG2: 'static,
     {
-        let task = self.task;
-        Action::new(async move {
-            let msg = task.await;
-            f(msg)
-        })
+        Action {
+            task: Box::pin(self.task.map(move |msg| msg.map(f))),
+        }
     }
 
-    /// get the next value
-    async fn next(&mut self) -> Option<MSG> {
-        // return None is already done since awaiting it again is an error
-        if self.done {
-            None
-        } else {
-            let msg = self.task.as_mut().await;
-            // mark as done
-            self.done = true;
-            Some(msg)
-        }
+    /// run a follow-up future with the msg of this task
+    fn and_then<F, FUT, MSG2>(self, f: F) -> Action<MSG2>
+    where
+        F: Fn(MSG) -> FUT + 'static,
+        FUT: Future<Output = MSG2> + 'static,
+        MSG2: 'static,
+    {
+        let task = self.into_task();
+        Action::from_task(async move {
+            match task.await {
+                Some(msg) => Some(f(msg).await),
+                None => None,
+            }
+        })
     }
 }
 
@@ -205,21 +519,52 @@ where
     }
 }
 
-#[cfg(feature = "with-dom")]
 /// Sub is a recurring operation
 pub struct Sub<MSG> {
-    pub(crate) receiver: UnboundedReceiver<MSG>,
+    pub(crate) receiver: Pin<Box<dyn Stream<Item = MSG>>>,
+    pub(crate) listening: Listening,
+}
+
+/// keeps the closure of a recurring task alive until it is dropped
+#[derive(Default)]
+pub(crate) struct Listening {
     /// store the associated closures so it is not dropped before being event executed
-    pub(crate) event_closure: Closure<dyn FnMut(web_sys::Event)>,
+    #[cfg(feature = "with-dom")]
+    pub(crate) _event_closure: Option<Closure<dyn FnMut(web_sys::Event)>>,
+    /// removes the event listener or clears the interval of the closure
+    pub(crate) teardown: Teardown,
+}
+
+/// the function which removes the event listener or clears the interval of a recurring Cmd,
+/// shared between the Cmd and its [`CmdHandle`] and taken by whichever runs it first
+pub(crate) type Teardown = Rc<RefCell<Option<Box<dyn FnOnce()>>>>;
+
+/// call the teardown if it is not called yet
+fn run_teardown(teardown: &Teardown) {
+    // taken before it is called, so the teardown can drop the other references to it
+    let teardown = teardown.borrow_mut().take();
+    if let Some(teardown) = teardown {
+        teardown();
+    }
+}
+
+impl Drop for Listening {
+    // the event listener is removed before the closure is dropped
+    fn drop(&mut self) {
+        run_teardown(&self.teardown);
+    }
 }
 
-#[cfg(feature = "with-dom")]
 impl<MSG> Sub<MSG>
 where
     MSG: 'static,
 {
-    async fn next(&mut self) -> Option<MSG> {
-        self.receiver.next().await
+    /// the msgs are no longer received when aborted
+    fn abortable(self, registration: AbortRegistration) -> Self {
+        Self {
+            receiver: Box::pin(Abortable::new(self.receiver, registration)),
+            ..self
+        }
     }
 
     /// apply a function to the msg to create a different task which has a different msg
@@ -228,21 +573,69 @@ where
         F: Fn(MSG) -> MSG2 + 'static,
         MSG2: 'static,
     {
-        let (mut tx, rx) = mpsc::unbounded();
+        Sub {
+            receiver: Box::pin(self.receiver.map(f)),
+            listening: self.listening,
+        }
+    }
+
+    /// run a follow-up future with each msg, the msgs are emitted in order
+    fn and_then<F, FUT, MSG2>(self, f: F) -> Sub<MSG2>
+    where
+        F: Fn(MSG) -> FUT + 'static,
+        FUT: Future<Output = MSG2> + 'static,
+        MSG2: 'static,
+    {
+        Sub {
+            receiver: Box::pin(self.receiver.then(f)),
+            listening: self.listening,
+        }
+    }
+
+    /// the future of the first msg, the event listener is removed once it is received
+    /// or when the future is dropped
+    fn into_first(self) -> Pin<Box<dyn Future<Output = Option<MSG>>>> {
         let Sub {
             mut receiver,
-            event_closure,
+            listening,
         } = self;
+        Box::pin(async move {
+            let msg = receiver.next().await;
+            drop(listening);
+            msg
+        })
+    }
+}
 
-        crate::dom::spawn_local(async move {
-            while let Some(msg) = receiver.next().await {
-                tx.start_send(f(msg)).expect("must send");
-            }
-        });
+#[cfg(all(test, feature = "with-dom"))]
+mod test {
+    use super::*;
+    use std::cell::Cell;
 
-        Sub {
-            receiver: rx,
-            event_closure,
-        }
+    fn counted_teardown(count: &Rc<Cell<u32>>) -> Cmd<()> {
+        let mut cmd = Cmd::from_stream(stream::pending());
+        let count = Rc::clone(count);
+        cmd.set_teardown(move || count.set(count.get() + 1));
+        cmd
+    }
+
+    #[test]
+    fn teardown_runs_once_when_dropped_then_cancelled() {
+        let count = Rc::new(Cell::new(0));
+        let (cmd, handle) = counted_teardown(&count).cancellable();
+        drop(cmd);
+        assert_eq!(count.get(), 1);
+        handle.cancel();
+        assert_eq!(count.get(), 1);
+    }
+
+    #[test]
+    fn teardown_runs_once_when_cancelled_then_dropped() {
+        let count = Rc::new(Cell::new(0));
+        let (cmd, handle) = counted_teardown(&count).cancellable();
+        handle.cancel();
+        assert_eq!(count.get(), 1);
+        drop(cmd);
+        assert_eq!(count.get(), 1);
     }
 }
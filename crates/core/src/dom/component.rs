@@ -173,7 +173,7 @@ impl<MSG> StatelessModel<MSG> {
     }
 
     /// return the attribute values of the view node matching the attribute name `name`
-    pub fn attribute_value(&self, name: &AttributeName) -> Option<Vec<&AttributeValue<MSG>>> {
+    pub fn attribute_value(&self, name: &str) -> Option<Vec<&AttributeValue<MSG>>> {
         self.view.attribute_value(name)
     }
 
@@ -3,6 +3,7 @@
 //!
 use crate::dom::Program;
 use crate::dom::{Application, Cmd, Effects};
+use futures::StreamExt;
 use wasm_bindgen_futures::spawn_local;
 
 /// Dispatch is a command to be executed by the system.
@@ -115,7 +116,9 @@ where
 {
     fn from(task: Cmd<APP::MSG>) -> Self {
         Dispatch::new(move |program| {
-            for mut command in task.commands.into_iter() {
+            for command in task.commands.into_iter() {
+                // the command is dropped once the program is unmounted
+                let mut command = command.into_stream().take_until(program.lifetime.ended());
                 let program = program.downgrade();
                 spawn_local(async move {
                     let mut program = program.upgrade().expect("upgrade");
@@ -1,7 +1,7 @@
 use crate::dom::document;
-use crate::dom::{dom_node::intern, Cmd};
+use crate::dom::Cmd;
 use futures::channel::mpsc;
-use wasm_bindgen::{prelude::*, JsCast};
+use wasm_bindgen::prelude::*;
 
 /// Provides function for document related functions
 #[derive(Clone, Copy)]
@@ -21,12 +21,6 @@ impl Document {
                 let msg = cb(selection);
                 tx.start_send(msg).expect("send");
             });
-        document()
-            .add_event_listener_with_callback(
-                intern("selectionchange"),
-                closure_cb.as_ref().unchecked_ref(),
-            )
-            .expect("add event callback");
-        Cmd::recurring(rx, closure_cb)
+        Cmd::recurring_listener(&document(), "selectionchange", rx, closure_cb)
     }
 }
@@ -24,7 +24,7 @@ pub struct DomAttr {
     /// namespace of the attribute
     pub namespace: Option<&'static str>,
     /// the name of the attribute
-    pub name: &'static str,
+    pub name: AttributeName,
     /// the value of the attribute
     pub value: Vec<DomAttrValue>,
 }
@@ -81,11 +81,7 @@ impl DomAttr {
     }
 
     /// set the style of this element
-    pub(crate) fn set_element_style(
-        element: &Element,
-        attr_name: AttributeName,
-        styles: Vec<Style>,
-    ) {
+    pub(crate) fn set_element_style(element: &Element, attr_name: &str, styles: Vec<Style>) {
         if let Some(merged_styles) = Style::merge_to_string(&styles) {
             // set the styles
             element
@@ -103,7 +99,7 @@ impl DomAttr {
     /// set simple values
     pub(crate) fn set_element_simple_values(
         element: &Element,
-        attr_name: AttributeName,
+        attr_name: &str,
         attr_namespace: Option<Namespace>,
         plain_values: Vec<Value>,
     ) {
@@ -185,7 +181,7 @@ impl DomAttr {
             DomAttr::set_disabled(element, false);
         }
         //actually remove the element
-        element.remove_attribute(intern(attr.name))?;
+        element.remove_attribute(intern(&attr.name))?;
 
         Ok(())
     }
@@ -10,7 +10,7 @@ use crate::{
     dom::events::MountEvent,
     dom::{Application, Program},
     vdom,
-    vdom::{Attribute, Leaf},
+    vdom::{Attribute, AttributeName, Leaf},
 };
 use indexmap::IndexMap;
 use std::borrow::Cow;
@@ -22,7 +22,7 @@ use wasm_bindgen::{closure::Closure, JsCast, JsValue};
 use web_sys::{self, Node};
 
 pub(crate) type EventClosure = Closure<dyn FnMut(web_sys::Event)>;
-pub type NamedEventClosures = IndexMap<&'static str, EventClosure>;
+pub type NamedEventClosures = IndexMap<AttributeName, EventClosure>;
 
 /// A counter part of the vdom Node
 /// This is needed, so that we can
@@ -455,7 +455,8 @@ impl DomNode {
             DomInner::Element {
                 element, listeners, ..
             } => {
-                let attr_name = intern(attr.name);
+                let name = attr.name.clone();
+                let attr_name = intern(&name);
                 let attr_namespace = attr.namespace;
 
                 let GroupedDomAttrValues {
@@ -468,14 +469,12 @@ impl DomNode {
                     .expect("event listeners");
                 let is_none = listeners.borrow().is_none();
                 if is_none {
-                    let listener_closures: IndexMap<
-                        &'static str,
-                        Closure<dyn FnMut(web_sys::Event)>,
-                    > = IndexMap::from_iter(event_callbacks.into_iter().map(|c| (attr_name, c)));
+                    let listener_closures: NamedEventClosures =
+                        IndexMap::from_iter(event_callbacks.into_iter().map(|c| (name.clone(), c)));
                     *listeners.borrow_mut() = Some(listener_closures);
                 } else if let Some(listeners) = listeners.borrow_mut().as_mut() {
                     for event_cb in event_callbacks.into_iter() {
-                        listeners.insert(attr_name, event_cb);
+                        listeners.insert(name.clone(), event_cb);
                     }
                 }
 
@@ -509,7 +508,7 @@ impl DomNode {
     /// attach and event listener to an event target
     pub(crate) fn add_event_dom_listeners(
         target: &web_sys::EventTarget,
-        attr_name: &'static str,
+        attr_name: &str,
         event_listeners: &[EventClosure],
     ) -> Result<(), JsValue> {
         for event_cb in event_listeners.iter() {
@@ -531,6 +530,59 @@ impl DomNode {
         Ok(())
     }
 
+    /// remove the event listeners of this node and its descendants from the DOM,
+    /// the closures are dropped along with them
+    pub(crate) fn remove_listeners(&self) {
+        match &self.inner {
+            DomInner::Element {
+                element,
+                listeners,
+                children,
+                ..
+            } => {
+                if let Some(listeners) = listeners.borrow_mut().take() {
+                    for (event_name, listener) in listeners.iter() {
+                        element
+                            .remove_event_listener_with_callback(
+                                intern(event_name),
+                                listener.as_ref().unchecked_ref(),
+                            )
+                            .expect("must remove event listener");
+                    }
+                }
+                for child in children.borrow().iter() {
+                    child.remove_listeners();
+                }
+            }
+            DomInner::Fragment { children, .. } => {
+                for child in children.borrow().iter() {
+                    child.remove_listeners();
+                }
+            }
+            DomInner::StatefulComponent { dom_node, .. } => dom_node.remove_listeners(),
+            _ => (),
+        }
+    }
+
+    /// remove this node from its parent in the DOM,
+    /// the children of a fragment are removed from where they are appended to
+    pub(crate) fn detach(&self) {
+        match &self.inner {
+            DomInner::Fragment { children, .. } => {
+                for child in children.borrow().iter() {
+                    child.detach();
+                }
+            }
+            DomInner::Symbol(_) => (),
+            _ => {
+                let node = self.as_node();
+                if let Some(parent) = node.parent_node() {
+                    parent.remove_child(&node).expect("must remove child");
+                }
+            }
+        }
+    }
+
     /// always dispatch the mount event on stateful component
     /// dispatch mount event to element that has on_mount callback set.
     fn should_dispatch_mount_event(&self) -> bool {
@@ -790,6 +842,9 @@ where
     #[allow(unused)]
     pub(crate) fn create_stateless_component(&self, comp: &StatelessModel<APP::MSG>) -> DomNode {
         let comp_view = &comp.view;
+        if let Some(dom_node) = self.create_dom_node_from_template(comp_view) {
+            return dom_node;
+        }
         let real_comp_view = comp_view.unwrap_template_ref();
         self.create_dom_node(real_comp_view)
     }
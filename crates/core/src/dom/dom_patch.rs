@@ -113,7 +113,7 @@ impl DomNode {
 
     pub(crate) fn find_all_nodes(
         &self,
-        nodes_to_find: &[(&TreePath, Option<&&'static str>)],
+        nodes_to_find: &[(&TreePath, Option<&str>)],
     ) -> IndexMap<TreePath, (DomNode, DomNode)> {
         let mut nodes_to_patch = IndexMap::with_capacity(nodes_to_find.len());
         for (path, tag) in nodes_to_find {
@@ -149,7 +149,7 @@ where
     pub(crate) fn convert_attr(&self, attr: &Attribute<APP::MSG>) -> DomAttr {
         DomAttr {
             namespace: attr.namespace,
-            name: attr.name,
+            name: attr.name.clone(),
             value: attr
                 .value
                 .iter()
@@ -204,7 +204,7 @@ where
         target_node: &DomNode,
         patches: &[Patch<APP::MSG>],
     ) -> Result<Vec<DomPatch>, JsValue> {
-        let nodes_to_find: Vec<(&TreePath, Option<&&'static str>)> = patches
+        let nodes_to_find: Vec<(&TreePath, Option<&str>)> = patches
             .iter()
             .map(|patch| (patch.path(), patch.tag()))
             .chain(
@@ -223,7 +223,7 @@ where
             if let Some((target_node, target_parent)) = nodes_lookup.get(patch_path) {
                 let target_tag = target_node.tag();
                 if let (Some(patch_tag), Some(target_tag)) = (patch_tag, target_tag) {
-                    if **patch_tag != target_tag{
+                    if patch_tag != target_tag{
                         panic!(
                             "expecting a tag: {patch_tag:?}, but found: {target_tag:?}"
                         );
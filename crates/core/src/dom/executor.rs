@@ -0,0 +1,177 @@
+//! Runs the update loop of an application without a browser,
+//! so the msgs emitted by the returned `Cmd`s can be asserted in native tests.
+use crate::dom::{Application, Cmd};
+use crate::vdom::Node;
+use futures::stream::SelectAll;
+use futures::{executor, FutureExt, Stream, StreamExt};
+use std::collections::VecDeque;
+use std::pin::Pin;
+
+/// Executes the `Cmd`s returned by the application and feeds their msgs back into `update`,
+/// without mounting the application into the DOM.
+///
+/// The `Cmd`s which use the browser apis such as `Http`, `Window` and `Time` can not be run
+/// natively, use `Cmd::new` with a mock future or `Cmd::from_stream` instead.
+///
+/// # Example
+/// ```rust
+/// use sauron::{dom::Executor, *};
+///
+/// #[derive(Clone, Debug, PartialEq)]
+/// enum Msg {
+///     Load,
+///     Loaded(Vec<String>),
+/// }
+///
+/// #[derive(Default)]
+/// struct App {
+///     items: Vec<String>,
+/// }
+///
+/// impl Application for App {
+///     type MSG = Msg;
+///
+///     fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+///         match msg {
+///             Msg::Load => Cmd::new(async { Msg::Loaded(vec!["a".to_string()]) }),
+///             Msg::Loaded(items) => {
+///                 self.items = items;
+///                 Cmd::none()
+///             }
+///         }
+///     }
+///
+///     fn view(&self) -> Node<Msg> {
+///         ul([], self.items.iter().map(|item| li([], [text(item)])))
+///     }
+/// }
+///
+/// let mut executor = Executor::new(App::default());
+/// executor.dispatch(Msg::Load);
+/// executor.settle();
+/// assert_eq!(executor.app().items, ["a"]);
+/// assert_eq!(executor.log(), [Msg::Load, Msg::Loaded(vec!["a".to_string()])]);
+/// ```
+pub struct Executor<APP>
+where
+    APP: Application,
+{
+    app: APP,
+    /// the msgs which are not yet passed to `update`
+    queue: VecDeque<APP::MSG>,
+    /// the msgs of the `Cmd`s which are still running
+    pending: SelectAll<Pin<Box<dyn Stream<Item = APP::MSG>>>>,
+    /// the msgs passed to `update`, in order
+    log: Vec<APP::MSG>,
+}
+
+impl<APP> Executor<APP>
+where
+    APP: Application,
+    APP::MSG: Clone + 'static,
+{
+    /// create an executor for this application, `init` is not called
+    pub fn new(app: APP) -> Self {
+        Self {
+            app,
+            queue: VecDeque::new(),
+            pending: SelectAll::new(),
+            log: vec![],
+        }
+    }
+
+    /// call the `init` of the application and run its `Cmd`, as when it is mounted
+    pub fn init(&mut self) {
+        let cmd = self.app.init();
+        self.spawn(cmd);
+        self.settle();
+    }
+
+    /// queue the msg, it is passed to `update` when the executor is settled or run
+    pub fn dispatch(&mut self, msg: APP::MSG) {
+        self.queue.push_back(msg);
+    }
+
+    /// queue the msgs in order
+    pub fn dispatch_multiple(&mut self, msgs: impl IntoIterator<Item = APP::MSG>) {
+        self.queue.extend(msgs);
+    }
+
+    /// run the `Cmd` along with the `Cmd`s of the application
+    pub fn spawn(&mut self, cmd: Cmd<APP::MSG>) {
+        self.pending.extend(
+            cmd.commands
+                .into_iter()
+                .map(|command| command.into_stream()),
+        );
+    }
+
+    /// update the application with the queued msgs and the msgs of the `Cmd`s which are ready,
+    /// until there are no more msgs which can be emitted without waiting.
+    /// The `Cmd`s which are still waiting, such as an endless stream, are kept running
+    pub fn settle(&mut self) {
+        loop {
+            self.update_queued();
+            match self.pending.next().now_or_never() {
+                Some(Some(msg)) => self.queue.push_back(msg),
+                // all the cmds are done, or the rest have to wait
+                Some(None) | None => break,
+            }
+        }
+    }
+
+    /// update the application with the queued msgs and block the current thread until all
+    /// the `Cmd`s are done, this never returns if a `Cmd` is an endless stream
+    pub fn run_until_done(&mut self) {
+        loop {
+            self.update_queued();
+            match executor::block_on(self.pending.next()) {
+                Some(msg) => self.queue.push_back(msg),
+                None => break,
+            }
+        }
+    }
+
+    fn update_queued(&mut self) {
+        while let Some(msg) = self.queue.pop_front() {
+            self.log.push(msg.clone());
+            let cmd = self.app.update(msg);
+            self.spawn(cmd);
+        }
+    }
+
+    /// the state of the application
+    pub fn app(&self) -> &APP {
+        &self.app
+    }
+
+    /// the state of the application which can be modified, ie: to setup a test case
+    pub fn app_mut(&mut self) -> &mut APP {
+        &mut self.app
+    }
+
+    /// the current view of the application
+    pub fn view(&self) -> Node<APP::MSG> {
+        self.app.view()
+    }
+
+    /// all the msgs passed to `update` so far, in order
+    pub fn log(&self) -> &[APP::MSG] {
+        &self.log
+    }
+
+    /// the number of `Cmd`s which are still running
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// returns true if there are no queued msgs and no running `Cmd`s
+    pub fn is_settled(&self) -> bool {
+        self.queue.is_empty() && self.pending.is_empty()
+    }
+
+    /// the application, the `Cmd`s which are still running are dropped
+    pub fn into_app(self) -> APP {
+        self.app
+    }
+}
@@ -0,0 +1,67 @@
+//! Middlewares see every msg dispatched in the program before it is passed to `update`,
+//! and the `Cmd` returned by `update`.
+//! This is used for the concerns shared by all the msgs such as logging, guards or debouncing.
+use crate::dom::Cmd;
+
+/// What to do with the msg intercepted by a middleware
+pub enum Intercept<MSG> {
+    /// pass the msg, which could be modified, to the next middleware and then to `update`
+    Continue(MSG),
+    /// the msg is not passed to `update`
+    Discard,
+    /// the msg is not passed to `update` right away, instead the msgs emitted by this `Cmd`
+    /// are passed to the next middlewares and then to `update`, ie: to delay a msg
+    Defer(Cmd<MSG>),
+}
+
+/// A middleware in the dispatch of a [`Program`](crate::dom::Program).
+/// The middlewares are called in the order they are added
+///
+/// # Example
+/// ```rust,ignore
+/// struct Debounce {
+///     handle: Option<CmdHandle>,
+/// }
+///
+/// impl Middleware<Msg> for Debounce {
+///     fn before_update(&mut self, msg: Msg) -> Intercept<Msg> {
+///         match msg {
+///             Msg::Search(query) => {
+///                 // the previous search which is still waiting is cancelled
+///                 if let Some(handle) = self.handle.take() {
+///                     handle.cancel();
+///                 }
+///                 let (cmd, handle) = Cmd::new(async move {
+///                     delay(300).await;
+///                     Msg::Search(query)
+///                 })
+///                 .cancellable();
+///                 self.handle = Some(handle);
+///                 Intercept::Defer(cmd)
+///             }
+///             msg => Intercept::Continue(msg),
+///         }
+///     }
+/// }
+///
+/// program.add_middleware(Debounce { handle: None });
+/// ```
+pub trait Middleware<MSG> {
+    /// called with each dispatched msg before it is passed to `update`
+    fn before_update(&mut self, msg: MSG) -> Intercept<MSG>;
+
+    /// called with the `Cmd` returned by `update`, the returned `Cmd` is executed instead
+    fn after_update(&mut self, cmd: Cmd<MSG>) -> Cmd<MSG> {
+        cmd
+    }
+}
+
+/// a function which only intercepts the msgs, ie: to log them
+impl<MSG, F> Middleware<MSG> for F
+where
+    F: FnMut(MSG) -> Intercept<MSG>,
+{
+    fn before_update(&mut self, msg: MSG) -> Intercept<MSG> {
+        self(msg)
+    }
+}
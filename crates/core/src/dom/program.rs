@@ -1,18 +1,21 @@
 use crate::dom::program::app_context::WeakContext;
-#[cfg(feature = "with-raf")]
 use crate::dom::request_animation_frame;
-#[cfg(feature = "with-ric")]
 use crate::dom::request_idle_callback;
+use crate::dom::scheduler::Scheduling;
+use crate::dom::subscription::ActiveSubscriptions;
 use crate::dom::DomNode;
 use crate::dom::SkipDiff;
 use crate::dom::SkipPath;
+use crate::dom::{delay, spawn_local, Cmd, Intercept, Middleware, Recorder, Subscription};
 use crate::dom::{document, now, IdleDeadline, Measurements};
 use crate::dom::{util::body, AnimationFrameHandle, Application, DomPatch, IdleCallbackHandle};
+use crate::dom::{Schedule, Scheduler};
 use crate::html::{self, attributes::class, text};
 use crate::vdom;
 use crate::vdom::diff;
 use crate::vdom::diff_recursive;
 use crate::vdom::Patch;
+use futures::StreamExt;
 use std::collections::hash_map::DefaultHasher;
 use std::collections::VecDeque;
 use std::hash::{Hash, Hasher};
@@ -27,20 +30,14 @@ use wasm_bindgen::{JsCast, JsValue};
 use web_sys;
 
 pub(crate) use app_context::AppContext;
+pub(crate) use lifetime::Lifetime;
 pub use mount_procedure::{MountAction, MountProcedure, MountTarget};
 
 
-
-thread_local! {
-    static CANCEL_CNT: RefCell<i32> = RefCell::new(0);
-}
-
-thread_local! {
-    static UPDATE_CNT: RefCell<i32> = RefCell::new(0);
-}
-
 mod app_context;
+mod lifetime;
 mod mount_procedure;
+mod template;
 
 /// Program handle the lifecycle of the APP
 pub struct Program<APP>
@@ -68,6 +65,18 @@ where
 
     /// keep track of the time when the dom is last updated
     pub(crate) last_update: Rc<RefCell<Option<f64>>>,
+
+    /// the event listeners and intervals of the app subscriptions
+    pub(crate) active_subscriptions: Rc<RefCell<ActiveSubscriptions<APP::MSG>>>,
+
+    /// the style elements injected into the document by this program
+    pub(crate) injected_styles: Rc<RefCell<Vec<DomNode>>>,
+
+    /// ends when the program is unmounted, which stops the running cmds
+    pub(crate) lifetime: Lifetime,
+
+    /// when the dispatched msgs are applied and the view is rendered
+    pub(crate) scheduling: Rc<RefCell<Scheduling>>,
 }
 
 pub struct WeakProgram<APP>
@@ -81,6 +90,10 @@ where
     idle_callback_handles: Weak<RefCell<Vec<IdleCallbackHandle>>>,
     animation_frame_handles: Weak<RefCell<Vec<AnimationFrameHandle>>>,
     last_update: Weak<RefCell<Option<f64>>>,
+    active_subscriptions: Weak<RefCell<ActiveSubscriptions<APP::MSG>>>,
+    injected_styles: Weak<RefCell<Vec<DomNode>>>,
+    lifetime: Lifetime,
+    scheduling: Weak<RefCell<Scheduling>>,
 }
 
 impl<APP> WeakProgram<APP>
@@ -96,6 +109,9 @@ where
         let idle_callback_handles = self.idle_callback_handles.upgrade()?;
         let animation_frame_handles = self.animation_frame_handles.upgrade()?;
         let last_update = self.last_update.upgrade()?;
+        let active_subscriptions = self.active_subscriptions.upgrade()?;
+        let injected_styles = self.injected_styles.upgrade()?;
+        let scheduling = self.scheduling.upgrade()?;
         Some(Program {
             app_context,
             root_node,
@@ -104,6 +120,10 @@ where
             idle_callback_handles,
             animation_frame_handles,
             last_update,
+            active_subscriptions,
+            injected_styles,
+            lifetime: self.lifetime.clone(),
+            scheduling,
         })
     }
 }
@@ -121,6 +141,10 @@ where
             idle_callback_handles: Weak::clone(&self.idle_callback_handles),
             animation_frame_handles: Weak::clone(&self.animation_frame_handles),
             last_update: Weak::clone(&self.last_update),
+            active_subscriptions: Weak::clone(&self.active_subscriptions),
+            injected_styles: Weak::clone(&self.injected_styles),
+            lifetime: self.lifetime.clone(),
+            scheduling: Weak::clone(&self.scheduling),
         }
     }
 }
@@ -139,6 +163,10 @@ where
             idle_callback_handles: Rc::downgrade(&self.idle_callback_handles),
             animation_frame_handles: Rc::downgrade(&self.animation_frame_handles),
             last_update: Rc::downgrade(&self.last_update),
+            active_subscriptions: Rc::downgrade(&self.active_subscriptions),
+            injected_styles: Rc::downgrade(&self.injected_styles),
+            lifetime: self.lifetime.clone(),
+            scheduling: Rc::downgrade(&self.scheduling),
         }
     }
 }
@@ -156,6 +184,10 @@ where
             idle_callback_handles: Rc::clone(&self.idle_callback_handles),
             animation_frame_handles: Rc::clone(&self.animation_frame_handles),
             last_update: Rc::clone(&self.last_update),
+            active_subscriptions: Rc::clone(&self.active_subscriptions),
+            injected_styles: Rc::clone(&self.injected_styles),
+            lifetime: self.lifetime.clone(),
+            scheduling: Rc::clone(&self.scheduling),
         }
     }
 }
@@ -194,6 +226,8 @@ where
                 current_vdom: Rc::new(RefCell::new(app_view)),
                 pending_msgs: Rc::new(RefCell::new(VecDeque::new())),
                 pending_dispatches: Rc::new(RefCell::new(VecDeque::new())),
+                middlewares: Rc::new(RefCell::new(vec![])),
+                recorder: Rc::new(RefCell::new(None)),
             },
             root_node: Rc::new(RefCell::new(None)),
             mount_node: Rc::new(RefCell::new(None)),
@@ -201,9 +235,26 @@ where
             idle_callback_handles: Rc::new(RefCell::new(vec![])),
             animation_frame_handles: Rc::new(RefCell::new(vec![])),
             last_update: Rc::new(RefCell::new(None)),
+            active_subscriptions: Rc::new(RefCell::new(ActiveSubscriptions::new())),
+            injected_styles: Rc::new(RefCell::new(vec![])),
+            lifetime: Lifetime::new(),
+            scheduling: Rc::new(RefCell::new(Scheduling::default())),
         }
     }
 
+    /// use the scheduler to apply the dispatched msgs and render the view,
+    /// this is set before the program is mounted
+    /// # Example
+    /// ```rust,ignore
+    /// let mut program = Program::new(App::default())
+    ///     .with_scheduler(Scheduler::animation_frame().with_frame_budget(33.0));
+    /// program.mount(&body(), MountProcedure::append());
+    /// ```
+    pub fn with_scheduler(self, scheduler: Scheduler) -> Self {
+        self.scheduling.borrow_mut().scheduler = scheduler;
+        self
+    }
+
     /// executed after the program has been mounted
     fn after_mounted(&mut self) {
         // call the init of the component
@@ -215,6 +266,8 @@ where
         // dispatching / redraw
         init_cmd.emit(self.clone());
 
+        self.update_subscriptions();
+
         // inject the app's dynamic style after the emitting the init function and it's effects
         self.inject_dynamic_style();
 
@@ -339,6 +392,9 @@ where
     /// from template and patched by the difference of vdom_template and current app view.
     pub(crate) fn create_initial_view(&self) -> DomNode {
         let current_view = self.app_context.current_vdom();
+        if let Some(dom_node) = self.create_dom_node_from_template(&current_view) {
+            return dom_node;
+        }
         let real_view = current_view.unwrap_template_ref();
         self.create_dom_node(real_view)
     }
@@ -398,20 +454,6 @@ where
         self.after_mounted();
     }
 
-    #[cfg(feature = "with-ric")]
-    fn dispatch_pending_msgs_with_ric(&mut self) -> Result<(), JsValue> {
-        let program = Program::downgrade(&self);
-        let handle = request_idle_callback(move |deadline| {
-            let mut program = program.upgrade().expect("must upgrade");
-            program
-                .dispatch_pending_msgs(Some(deadline))
-                .expect("must execute")
-        })
-        .expect("must execute");
-        self.idle_callback_handles.borrow_mut().push(handle);
-        Ok(())
-    }
-
     /// executes pending msgs by calling the app update method with the msgs
     /// as parameters.
     /// If there is no deadline specified all the pending messages are executed
@@ -431,9 +473,7 @@ where
         }
         if !did_complete {
             log::info!("did not complete pending msgs in time.. dispatching the rest");
-            #[cfg(feature = "with-ric")]
-            self.dispatch_pending_msgs_with_ric()
-                .expect("must complete");
+            self.schedule_dispatch();
         }
         Ok(())
     }
@@ -441,27 +481,15 @@ where
     /// execute DOM changes in order to reflect the APP's view into the browser representation
     pub fn update_dom(&mut self) -> Result<(), JsValue> {
         let t1 = now();
-        //#[cfg(all(feature = "with-measure", feature = "with-debug"))]
-        if let Some(last_update) = self.last_update.borrow().as_ref() {
-            let frame_time = (1000_f64 / 60_f64).floor(); // 1s in 60 frames
-            let time_delta = t1 - last_update;
-            let remaining = frame_time - time_delta;
-            if time_delta < frame_time {
-                log::warn!("update is {remaining} too soon!... time_delta: {time_delta}, frame_time: {frame_time}");
-                let mut program = self.clone();
-                //#[cfg(feature = "with-debounce")]
-                crate::dom::request_timeout_callback(
-                    move||{
-                        program.update_dom().unwrap();
-                    }, remaining.round() as i32).unwrap();
-                log::info!("update is cancelled..");
-                CANCEL_CNT.with_borrow_mut(|c|*c += 1);
-                return Ok(())
+        let frame_budget = self.scheduling.borrow().scheduler.frame_budget;
+        let last_update = *self.last_update.borrow();
+        if let (Some(frame_budget), Some(last_update)) = (frame_budget, last_update) {
+            let remaining = frame_budget - (t1 - last_update);
+            if remaining > 0.0 {
+                self.defer_update_dom(remaining);
+                return Ok(());
             }
         }
-        log::info!("Doing and update...");
-        UPDATE_CNT.with_borrow_mut(|c|*c += 1);
-        log::info!("ratio(cancelled/update): {}/{}", CANCEL_CNT.with_borrow(|c|*c), UPDATE_CNT.with_borrow(|c|*c));
         // a new view is created due to the app update
         let view = self.app_context.view();
         let t2 = now();
@@ -528,18 +556,31 @@ where
         Ok(())
     }
 
+    /// render the view once the remaining frame budget has elapsed,
+    /// the msgs which are applied meanwhile are rendered in that same update
+    fn defer_update_dom(&self, remaining: f64) {
+        if std::mem::replace(&mut self.scheduling.borrow_mut().render_deferred, true) {
+            return;
+        }
+        let program = self.downgrade();
+        spawn_local(async move {
+            delay(remaining.ceil() as i32).await;
+            let Some(mut program) = program.upgrade() else {
+                return;
+            };
+            program.scheduling.borrow_mut().render_deferred = false;
+            if !program.lifetime.is_ended() {
+                program.update_dom().expect("must update dom");
+            }
+        });
+    }
+
     /// patch the DOM to reflect the App's view
     ///
     /// Note: This is in another function so as to allow tests to use this shared code
     pub fn queue_dom_patches(&mut self, dom_patches: Vec<DomPatch>) -> Result<(), JsValue> {
         self.pending_patches.borrow_mut().extend(dom_patches);
-
-        #[cfg(feature = "with-raf")]
-        self.apply_pending_patches_with_raf().expect("raf");
-
-        #[cfg(not(feature = "with-raf"))]
         self.apply_pending_patches().expect("raf");
-
         Ok(())
     }
 
@@ -579,18 +620,6 @@ where
         .expect("must convert patches")
     }
 
-    #[cfg(feature = "with-raf")]
-    fn apply_pending_patches_with_raf(&mut self) -> Result<(), JsValue> {
-        let program = Program::downgrade(&self);
-        let handle = request_animation_frame(move || {
-            let mut program = program.upgrade().expect("must upgrade");
-            program.apply_pending_patches().expect("must not error");
-        })
-        .expect("must execute");
-        self.animation_frame_handles.borrow_mut().push(handle);
-        Ok(())
-    }
-
     /// apply the pending patches into the DOM
     fn apply_pending_patches(&mut self) -> Result<(), JsValue> {
         if self.pending_patches.borrow().is_empty() {
@@ -602,56 +631,55 @@ where
         Ok(())
     }
 
-    #[cfg(feature = "with-ric")]
-    fn dispatch_inner_with_ric(&self) {
-        let program = Program::downgrade(&self);
-        let handle = request_idle_callback(move |deadline| {
-            if let Some(mut program) = program.upgrade() {
-                program.dispatch_inner(Some(deadline));
-            } else {
-                log::warn!("unable to upgrade program.. maybe try again next time..");
-            }
-        })
-        .expect("must execute");
-        self.idle_callback_handles.borrow_mut().push(handle);
-    }
-
-    #[allow(unused)]
-    #[cfg(feature = "with-raf")]
-    fn dispatch_inner_with_raf(&self) {
-        let program = Program::downgrade(&self);
-        let handle = request_animation_frame(move || {
-            let mut program = program.upgrade().expect("must upgrade");
-            program.dispatch_inner(None);
-        })
-        .expect("must execute");
-        self.animation_frame_handles.borrow_mut().push(handle);
-    }
-
-    fn dispatch_inner_with_priority_ric(&self) {
-        #[cfg(feature = "with-ric")]
-        self.dispatch_inner_with_ric();
-        #[cfg(not(feature = "with-ric"))]
-        {
-            #[cfg(feature = "with-raf")]
-            self.dispatch_inner_with_raf();
-
-            #[cfg(not(feature = "with-raf"))]
-            {
-                let program = Program::downgrade(self);
-                wasm_bindgen_futures::spawn_local(async move {
+    /// run the dispatch of the pending msgs according to the scheduler of the program,
+    /// unless a dispatch is already scheduled
+    fn schedule_dispatch(&self) {
+        if std::mem::replace(&mut self.scheduling.borrow_mut().dispatch_scheduled, true) {
+            return;
+        }
+        let program = self.downgrade();
+        let schedule = self.scheduling.borrow().scheduler.schedule.clone();
+        match schedule {
+            Schedule::Immediate => spawn_local(async move {
+                if let Some(mut program) = program.upgrade() {
+                    program.dispatch_inner(None);
+                }
+            }),
+            Schedule::AnimationFrame => {
+                let handle = request_animation_frame(move || {
                     if let Some(mut program) = program.upgrade() {
                         program.dispatch_inner(None);
-                    } else {
-                        log::warn!(
-                            "unable to upgrade program here, in dispatch_inner_with_priority_ric"
-                        );
                     }
                 })
+                .expect("must execute");
+                self.animation_frame_handles.borrow_mut().push(handle);
             }
+            Schedule::Idle => {
+                let handle = request_idle_callback(move |deadline| {
+                    if let Some(mut program) = program.upgrade() {
+                        program.dispatch_inner(Some(deadline));
+                    }
+                })
+                .expect("must execute");
+                self.idle_callback_handles.borrow_mut().push(handle);
+            }
+            // the msgs are applied when the program is flushed
+            Schedule::Manual => (),
+            Schedule::Custom(run) => run(Box::new(move || {
+                if let Some(mut program) = program.upgrade() {
+                    program.dispatch_inner(None);
+                }
+            })),
         }
     }
 
+    /// apply the pending msgs and render the view right away, regardless of the scheduler
+    /// and the frame budget, ie: to assert the view in tests right after dispatching msgs
+    pub fn flush(&mut self) {
+        *self.last_update.borrow_mut() = None;
+        self.dispatch_inner(None);
+    }
+
     /// This is called when an event is triggered in the html DOM.
     /// The sequence of things happening here:
     /// - The app component update is executed.
@@ -659,6 +687,11 @@ where
     /// - The view is reconstructed with the new state of the app.
     /// - The dom is updated with the newly reconstructed view.
     fn dispatch_inner(&mut self, deadline: Option<IdleDeadline>) {
+        if self.lifetime.is_ended() {
+            return;
+        }
+        // the msgs dispatched from here on are applied in the next dispatch
+        self.scheduling.borrow_mut().dispatch_scheduled = false;
         self.dispatch_pending_msgs(deadline)
             .expect("must dispatch msgs");
         // ensure that all pending msgs are all dispatched already
@@ -706,10 +739,32 @@ where
             );
         }
 
+        self.update_subscriptions();
+
         // execute this `cmd` batched pending_dispatches that may have resulted from updating the app
         cmd.emit(self.clone());
     }
 
+    /// add and remove the listeners of the app subscriptions, which may have changed after
+    /// the app is updated
+    fn update_subscriptions(&mut self) {
+        let subscription = self.app_context.subscriptions();
+        let mut active_subscriptions = self.active_subscriptions.borrow_mut();
+        if let Some(receiver) = active_subscriptions.take_receiver() {
+            let mut receiver = receiver.take_until(self.lifetime.ended());
+            let program = self.downgrade();
+            wasm_bindgen_futures::spawn_local(async move {
+                while let Some(msg) = receiver.next().await {
+                    let Some(mut program) = program.upgrade() else {
+                        break;
+                    };
+                    program.dispatch(msg);
+                }
+            });
+        }
+        active_subscriptions.sync(subscription);
+    }
+
     /// Inject a style to the global document
     fn inject_style(&mut self, class_names: String, style: &str) {
         let style_node = html::tags::style([class(class_names)], [text(style)]);
@@ -718,7 +773,8 @@ where
         let head = document().head().expect("must have a head");
         let head_node: web_sys::Node = head.unchecked_into();
         let dom_head = DomNode::from(head_node);
-        dom_head.append_children(vec![created_node]);
+        dom_head.append_children(vec![created_node.clone()]);
+        self.injected_styles.borrow_mut().push(created_node);
     }
 
     /// inject style element to the mount node
@@ -730,19 +786,141 @@ where
             .borrow_mut()
             .as_mut()
             .expect("mount node")
-            .append_children(vec![created_node]);
+            .append_children(vec![created_node.clone()]);
+        self.injected_styles.borrow_mut().push(created_node);
     }
 
     /// dispatch multiple MSG
     pub fn dispatch_multiple(&mut self, msgs: impl IntoIterator<Item = APP::MSG>) {
-        self.app_context.push_msgs(msgs);
-        self.dispatch_inner_with_priority_ric();
+        self.dispatch_from_middleware(0, msgs);
+    }
+
+    /// add a middleware which intercepts the msgs dispatched from now on,
+    /// it is called after the middlewares added before it
+    pub fn add_middleware(&self, middleware: impl Middleware<APP::MSG> + 'static) {
+        self.app_context
+            .middlewares
+            .borrow_mut()
+            .push(Box::new(middleware));
+    }
+
+    /// record the msgs passed to `update` from now on, replacing the previous recorder.
+    /// The current state of the application is the base state the msgs are replayed against
+    pub fn record(&self, recorder: &Recorder<APP>)
+    where
+        APP: Clone,
+        APP::MSG: Clone,
+    {
+        recorder.start_from(self.app().clone());
+        *self.app_context.recorder.borrow_mut() = Some(Box::new(recorder.clone()));
+    }
+
+    /// stop recording the msgs
+    pub fn stop_recording(&self) {
+        *self.app_context.recorder.borrow_mut() = None;
+    }
+
+    /// replace the state of the application and render its view, ie: to travel in time
+    pub(crate) fn replace_app(&mut self, app: APP) {
+        *self.app_context.app.borrow_mut() = app;
+        self.update_dom().expect("must update dom");
+        self.update_subscriptions();
+    }
+
+    /// pass the msgs to the middlewares starting from the nth middleware,
+    /// the msgs which are let through are dispatched
+    fn dispatch_from_middleware(&mut self, nth: usize, msgs: impl IntoIterator<Item = APP::MSG>) {
+        // the msgs dispatched after the program is unmounted are ignored
+        if self.lifetime.is_ended() {
+            return;
+        }
+        let mut passed = vec![];
+        'msgs: for mut msg in msgs {
+            let mut index = nth;
+            loop {
+                let intercepted = match self.app_context.middlewares.borrow_mut().get_mut(index) {
+                    Some(middleware) => middleware.before_update(msg),
+                    None => break,
+                };
+                index += 1;
+                match intercepted {
+                    Intercept::Continue(next_msg) => msg = next_msg,
+                    Intercept::Discard => continue 'msgs,
+                    Intercept::Defer(cmd) => {
+                        self.dispatch_deferred(index, cmd);
+                        continue 'msgs;
+                    }
+                }
+            }
+            passed.push(msg);
+        }
+        self.app_context.push_msgs(passed);
+        self.schedule_dispatch();
+    }
+
+    /// the msgs of the deferred cmd are passed to the middlewares starting from the nth
+    fn dispatch_deferred(&self, nth: usize, cmd: Cmd<APP::MSG>) {
+        for command in cmd.commands {
+            let program = self.downgrade();
+            let mut msgs = command.into_stream().take_until(self.lifetime.ended());
+            spawn_local(async move {
+                while let Some(msg) = msgs.next().await {
+                    let Some(mut program) = program.upgrade() else {
+                        break;
+                    };
+                    program.dispatch_from_middleware(nth, [msg]);
+                }
+            });
+        }
     }
 
     /// dispatch a single msg
     pub fn dispatch(&mut self, msg: APP::MSG) {
         self.dispatch_multiple([msg])
     }
+
+    /// remove the view of the app from the DOM along with its event listeners,
+    /// and stop the subscriptions, the running cmds and the scheduled dom updates.
+    /// The styles injected by the program are removed too.
+    ///
+    /// The msgs dispatched afterwards are ignored and the program can be dropped safely,
+    /// ie: `ManuallyDrop::into_inner(program)` when it is returned by `mount_to_body`.
+    /// # Example
+    /// ```rust,ignore
+    /// let mut program = Program::mount_to_body(App::default());
+    /// // ..
+    /// program.unmount();
+    /// drop(ManuallyDrop::into_inner(program));
+    /// ```
+    pub fn unmount(&mut self) {
+        if self.lifetime.is_ended() {
+            return;
+        }
+        // the running cmds and the deferred msgs are dropped, which removes their listeners
+        self.lifetime.end();
+        self.active_subscriptions
+            .borrow_mut()
+            .sync(Subscription::none());
+        self.idle_callback_handles.borrow_mut().clear();
+        self.animation_frame_handles.borrow_mut().clear();
+        self.pending_patches.borrow_mut().clear();
+        self.app_context.pending_msgs.borrow_mut().clear();
+        self.app_context.pending_dispatches.borrow_mut().clear();
+
+        if let Some(root_node) = self.root_node.borrow_mut().take() {
+            root_node.remove_listeners();
+            root_node.detach();
+        }
+        for style in self.injected_styles.borrow_mut().drain(..) {
+            style.detach();
+        }
+        *self.mount_node.borrow_mut() = None;
+    }
+
+    /// returns true if the program has been unmounted
+    pub fn is_unmounted(&self) -> bool {
+        self.lifetime.is_ended()
+    }
 }
 
 impl<APP> Program<APP>
@@ -1,6 +1,7 @@
+use crate::dom::recorder::Recording;
 #[cfg(feature = "with-measure")]
 use crate::dom::Measurements;
-use crate::dom::{Application, Dispatch};
+use crate::dom::{Application, Dispatch, Middleware, Subscription};
 use crate::vdom;
 use std::{
     cell::{Ref, RefCell},
@@ -9,6 +10,12 @@ use std::{
     rc::Weak,
 };
 
+/// the middlewares of a program, in the order they are added
+pub(crate) type Middlewares<MSG> = Vec<Box<dyn Middleware<MSG>>>;
+
+/// the recorder of the msgs passed to `update`, if recording
+pub(crate) type ActiveRecorder<APP> = Option<Box<dyn Recording<APP>>>;
+
 /// AppContext module pertains only to application state and manages objects that affects it.
 /// It has no access to the dom, threads or any of the processing details that Program has to do.
 pub(crate) struct AppContext<APP>
@@ -30,6 +37,12 @@ where
 
     /// pending cmds that hasn't been emited yet
     pub(crate) pending_dispatches: Rc<RefCell<VecDeque<Dispatch<APP>>>>,
+
+    /// the middlewares which intercept the msgs and the cmds, in the order they are added
+    pub(crate) middlewares: Rc<RefCell<Middlewares<APP::MSG>>>,
+
+    /// records the msgs passed to `update`
+    pub(crate) recorder: Rc<RefCell<ActiveRecorder<APP>>>,
 }
 
 pub(crate) struct WeakContext<APP>
@@ -40,6 +53,8 @@ where
     pub(crate) current_vdom: Weak<RefCell<vdom::Node<APP::MSG>>>,
     pub(crate) pending_msgs: Weak<RefCell<VecDeque<APP::MSG>>>,
     pub(crate) pending_dispatches: Weak<RefCell<VecDeque<Dispatch<APP>>>>,
+    pub(crate) middlewares: Weak<RefCell<Middlewares<APP::MSG>>>,
+    pub(crate) recorder: Weak<RefCell<ActiveRecorder<APP>>>,
 }
 
 impl<APP> WeakContext<APP>
@@ -51,11 +66,15 @@ where
         let current_vdom = self.current_vdom.upgrade()?;
         let pending_msgs = self.pending_msgs.upgrade()?;
         let pending_dispatches = self.pending_dispatches.upgrade()?;
+        let middlewares = self.middlewares.upgrade()?;
+        let recorder = self.recorder.upgrade()?;
         Some(AppContext {
             app,
             current_vdom,
             pending_msgs,
             pending_dispatches,
+            middlewares,
+            recorder,
         })
     }
 }
@@ -70,6 +89,8 @@ where
             current_vdom: Weak::clone(&self.current_vdom),
             pending_msgs: Weak::clone(&self.pending_msgs),
             pending_dispatches: Weak::clone(&self.pending_dispatches),
+            middlewares: Weak::clone(&self.middlewares),
+            recorder: Weak::clone(&self.recorder),
         }
     }
 }
@@ -84,6 +105,8 @@ where
             current_vdom: Rc::downgrade(&this.current_vdom),
             pending_msgs: Rc::downgrade(&this.pending_msgs),
             pending_dispatches: Rc::downgrade(&this.pending_dispatches),
+            middlewares: Rc::downgrade(&this.middlewares),
+            recorder: Rc::downgrade(&this.recorder),
         }
     }
     pub fn strong_count(&self) -> usize {
@@ -104,6 +127,8 @@ where
             current_vdom: Rc::clone(&self.current_vdom),
             pending_msgs: Rc::clone(&self.pending_msgs),
             pending_dispatches: Rc::clone(&self.pending_dispatches),
+            middlewares: Rc::clone(&self.middlewares),
+            recorder: Rc::clone(&self.recorder),
         }
     }
 }
@@ -119,6 +144,10 @@ where
     pub fn view(&self) -> vdom::Node<APP::MSG> {
         self.app.borrow().view()
     }
+    pub fn subscriptions(&self) -> Subscription<APP::MSG> {
+        self.app.borrow().subscriptions()
+    }
+
     pub fn dynamic_style(&self) -> String {
         self.app.borrow().style().join("")
     }
@@ -145,7 +174,17 @@ where
     }
 
     pub fn update_app(&mut self, msg: APP::MSG) -> Dispatch<APP> {
-        Dispatch::from(self.app.borrow_mut().update(msg))
+        if let Some(recorder) = self.recorder.borrow().as_ref() {
+            recorder.record_msg(&msg);
+        }
+        let mut cmd = self.app.borrow_mut().update(msg);
+        if let Some(recorder) = self.recorder.borrow().as_ref() {
+            recorder.record_snapshot(&self.app.borrow());
+        }
+        for middleware in self.middlewares.borrow_mut().iter_mut() {
+            cmd = middleware.after_update(cmd);
+        }
+        Dispatch::from(cmd)
     }
 
     /// return true if there are still pending msgs
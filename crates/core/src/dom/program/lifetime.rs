@@ -0,0 +1,37 @@
+use futures::channel::oneshot;
+use futures::future::{FutureExt, Shared};
+use std::{cell::RefCell, rc::Rc};
+
+/// Signals the tasks of a program such as the running cmds to stop, once it is unmounted
+#[derive(Clone)]
+pub(crate) struct Lifetime {
+    sender: Rc<RefCell<Option<oneshot::Sender<()>>>>,
+    ended: Shared<oneshot::Receiver<()>>,
+}
+
+impl Lifetime {
+    pub(crate) fn new() -> Self {
+        let (sender, receiver) = oneshot::channel();
+        Self {
+            sender: Rc::new(RefCell::new(Some(sender))),
+            ended: receiver.shared(),
+        }
+    }
+
+    /// end the lifetime, which resolves the futures returned by `ended`
+    pub(crate) fn end(&self) {
+        if let Some(sender) = self.sender.borrow_mut().take() {
+            let _ = sender.send(());
+        }
+    }
+
+    /// returns true if the lifetime has ended
+    pub(crate) fn is_ended(&self) -> bool {
+        self.sender.borrow().is_none()
+    }
+
+    /// a future which resolves once the lifetime has ended
+    pub(crate) fn ended(&self) -> Shared<oneshot::Receiver<()>> {
+        self.ended.clone()
+    }
+}
@@ -0,0 +1,106 @@
+//! The static skeleton of a `view!` is registered once per call site as a html `<template>`.
+//! Creating the view then clones the template and patches only the dynamic parts of the view.
+use crate::dom::{document, Application, DomNode, Program, SkipPath};
+use crate::vdom;
+use crate::vdom::{diff_recursive, Leaf, PatchType, TemplatedView, TreePath};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use wasm_bindgen::JsCast;
+
+thread_local! {
+    /// the registered templates, keyed by the call site of the `view!` macro
+    /// and the hash of its skeleton
+    static TEMPLATE_LOOKUP: RefCell<HashMap<&'static str, web_sys::Node>> = RefCell::new(HashMap::new());
+}
+
+impl<APP> Program<APP>
+where
+    APP: Application,
+{
+    /// create the dom node of a templated view by cloning its template,
+    /// then patching the dynamic parts of the view into it.
+    /// Returns None if the node is not a templated view or if the view can not be created
+    /// from its template, in which case the view is created the usual way.
+    pub(crate) fn create_dom_node_from_template(
+        &self,
+        node: &vdom::Node<APP::MSG>,
+    ) -> Option<DomNode> {
+        let vdom::Node::Leaf(Leaf::TemplatedView(view)) = node else {
+            return None;
+        };
+        let template = (view.template)();
+        let real_view = &*view.view;
+        // the root node is patched in place, which has to be an element of the same tag
+        match (&template, real_view) {
+            (vdom::Node::Element(template_elm), vdom::Node::Element(view_elm))
+                if template_elm.tag() == view_elm.tag() => {}
+            _ => return None,
+        }
+        // the mount event is dispatched only to elements created from the vdom
+        if has_mount_callback(real_view) {
+            return None;
+        }
+        let skip_diff = (view.skip_diff)().for_template(&template, real_view);
+        let patches = diff_recursive(
+            &template,
+            real_view,
+            &SkipPath::new(TreePath::root(), skip_diff),
+        );
+        let replaces_root = patches.iter().any(|patch| {
+            patch.path().is_empty() && matches!(patch.patch_type, PatchType::ReplaceNode { .. })
+        });
+        if replaces_root {
+            return None;
+        }
+        let dom_node = DomNode::from(self.clone_template(view, &template));
+        let dom_patches = self
+            .convert_patches(&dom_node, &patches)
+            .expect("must convert patches");
+        self.apply_dom_patches(dom_patches)
+            .expect("must apply patches");
+        Some(dom_node)
+    }
+
+    /// clone the template of this view, registering it first if this is the first time
+    /// the view is created
+    fn clone_template(
+        &self,
+        view: &TemplatedView<APP::MSG>,
+        template: &vdom::Node<APP::MSG>,
+    ) -> web_sys::Node {
+        let registered = TEMPLATE_LOOKUP.with_borrow(|lookup| lookup.get(view.call_site).cloned());
+        let template_node = registered.unwrap_or_else(|| {
+            let template_node = self.register_template(template);
+            TEMPLATE_LOOKUP.with_borrow_mut(|lookup| {
+                lookup.insert(view.call_site, template_node.clone());
+            });
+            template_node
+        });
+        template_node
+            .clone_node_with_deep(true)
+            .expect("must clone template")
+    }
+
+    /// create the dom node of the template inside a html `<template>`, so it stays inert
+    fn register_template(&self, template: &vdom::Node<APP::MSG>) -> web_sys::Node {
+        let template_element: web_sys::HtmlTemplateElement = document()
+            .create_element("template")
+            .expect("create template element")
+            .unchecked_into();
+        let template_node = self.create_dom_node(template).as_node();
+        template_element
+            .content()
+            .append_child(&template_node)
+            .expect("append to template")
+    }
+}
+
+/// returns true if this node or any of its descendant has a mount callback
+fn has_mount_callback<MSG>(node: &vdom::Node<MSG>) -> bool {
+    match node {
+        vdom::Node::Element(elm) => {
+            elm.has_mount_callback() || elm.children().iter().any(has_mount_callback)
+        }
+        vdom::Node::Leaf(_) => false,
+    }
+}
@@ -0,0 +1,268 @@
+//! Records the msgs dispatched in a program, so the sequence of msgs which led to a bug
+//! can be replayed, stepped through and exported.
+use crate::dom::{Application, Program};
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+
+/// A msg passed to `update`, along with the snapshot of the state after it is updated
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record<MSG> {
+    /// the msg passed to `update`
+    pub msg: MSG,
+    /// the serialized state of the application after the update, if snapshots are enabled
+    pub snapshot: Option<String>,
+}
+
+/// Keeps a bounded log of the msgs dispatched in a program, from the moment it is passed to
+/// [`Program::record`].
+///
+/// The `Cmd`s are not executed when the msgs are replayed, since the msgs emitted by the
+/// `Cmd`s are also recorded.
+/// The replay starts from the state of the application when it is passed to
+/// [`Program::record`].
+/// Once the log is full the oldest msgs are dropped, they are applied to the base state which
+/// the replay starts from, so the app can't travel back before the msgs which are kept.
+///
+/// # Example
+/// ```rust,ignore
+/// let recorder = Recorder::new(1000);
+/// program.record(&recorder);
+/// // ..
+/// recorder.step_backward(&mut program);
+/// log::info!("{}", recorder.to_json()?);
+/// ```
+pub struct Recorder<APP>
+where
+    APP: Application,
+{
+    history: Rc<RefCell<History<APP>>>,
+}
+
+struct History<APP>
+where
+    APP: Application,
+{
+    capacity: usize,
+    records: VecDeque<Record<APP::MSG>>,
+    /// the number of records which are applied to the app of the program,
+    /// this is less than the number of records when travelled back in time
+    position: usize,
+    /// the state which the records are replayed against, that is the application when the
+    /// recording started updated with the msgs which are dropped from the log.
+    /// This is `None` until it is recorded in a program or loaded from json
+    base: Option<APP>,
+    /// serialize the state of the application after each update
+    snapshot: Option<Snapshot<APP>>,
+}
+
+/// serialize the state of the application
+type Snapshot<APP> = Box<dyn Fn(&APP) -> String>;
+
+impl<APP> Clone for Recorder<APP>
+where
+    APP: Application,
+{
+    fn clone(&self) -> Self {
+        Self {
+            history: Rc::clone(&self.history),
+        }
+    }
+}
+
+impl<APP> Recorder<APP>
+where
+    APP: Application + Clone,
+    APP::MSG: Clone,
+{
+    /// keeps up to `capacity` msgs
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            history: Rc::new(RefCell::new(History {
+                capacity,
+                records: VecDeque::new(),
+                position: 0,
+                base: None,
+                snapshot: None,
+            })),
+        }
+    }
+
+    /// start recording from this state of the application, the previous records are removed
+    /// since they are replayed against the previous base state
+    pub(crate) fn start_from(&self, app: APP) {
+        let mut history = self.history.borrow_mut();
+        history.base = Some(app);
+        history.records.clear();
+        history.position = 0;
+    }
+
+    /// also record the state of the application serialized with `snapshot` after each update
+    pub fn with_snapshot(self, snapshot: impl Fn(&APP) -> String + 'static) -> Self {
+        self.history.borrow_mut().snapshot = Some(Box::new(snapshot));
+        self
+    }
+
+    /// the recorded msgs along with their snapshot, from the oldest
+    pub fn records(&self) -> Vec<Record<APP::MSG>> {
+        self.history.borrow().records.iter().cloned().collect()
+    }
+
+    /// the number of recorded msgs
+    pub fn len(&self) -> usize {
+        self.history.borrow().records.len()
+    }
+
+    /// returns true if no msg is recorded
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// the number of recorded msgs which are applied to the application of the program
+    pub fn position(&self) -> usize {
+        self.history.borrow().position
+    }
+
+    /// remove all the recorded msgs, the state after the applied msgs becomes the base state
+    /// which the msgs recorded afterwards are replayed against
+    pub fn clear(&self) {
+        if self.history.borrow().base.is_none() {
+            return;
+        }
+        let base = self.replay(self.position());
+        let mut history = self.history.borrow_mut();
+        history.base = Some(base);
+        history.records.clear();
+        history.position = 0;
+    }
+
+    /// update a copy of the base state with the first `count` recorded msgs.
+    ///
+    /// # Panics
+    /// if the recorder is not yet passed to [`Program::record`] nor loaded from json
+    pub fn replay(&self, count: usize) -> APP {
+        let history = self.history.borrow();
+        let mut app = history
+            .base
+            .clone()
+            .expect("the recorder must be recording a program or loaded from json");
+        for record in history.records.iter().take(count) {
+            // the msgs of the cmds are recorded too
+            let _cmd = app.update(record.msg.clone());
+        }
+        app
+    }
+
+    /// replace the application of the program with the state after the first `position`
+    /// recorded msgs and render its view.
+    /// The msgs dispatched afterwards replace the records after this position
+    pub fn travel_to(&self, program: &mut Program<APP>, position: usize) {
+        let position = position.min(self.len());
+        let app = self.replay(position);
+        self.history.borrow_mut().position = position;
+        program.replace_app(app);
+    }
+
+    /// go back to the state before the last applied msg
+    pub fn step_backward(&self, program: &mut Program<APP>) {
+        let position = self.position().saturating_sub(1);
+        self.travel_to(program, position);
+    }
+
+    /// apply the next recorded msg, after it is stepped backward
+    pub fn step_forward(&self, program: &mut Program<APP>) {
+        let position = self.position() + 1;
+        self.travel_to(program, position);
+    }
+
+    /// the recorded msgs and snapshots as json, ie: to be attached to a bug report
+    #[cfg(feature = "with-serde")]
+    pub fn to_json(&self) -> Result<String, serde_json::Error>
+    where
+        APP::MSG: serde::Serialize,
+    {
+        let records = self
+            .history
+            .borrow()
+            .records
+            .iter()
+            .map(|record| {
+                Ok(serde_json::json!({
+                    "msg": serde_json::to_value(&record.msg)?,
+                    "snapshot": record.snapshot,
+                }))
+            })
+            .collect::<Result<Vec<_>, serde_json::Error>>()?;
+        serde_json::to_string(&records)
+    }
+
+    /// replace the recorded msgs with the msgs exported with [`Recorder::to_json`],
+    /// which are replayed against the `fresh` application.
+    /// Use [`Recorder::travel_to`] to replay them in the program
+    #[cfg(feature = "with-serde")]
+    pub fn load_json(&self, json: &str, fresh: APP) -> Result<(), serde_json::Error>
+    where
+        APP::MSG: serde::de::DeserializeOwned,
+    {
+        let values: Vec<serde_json::Value> = serde_json::from_str(json)?;
+        let records = values
+            .into_iter()
+            .map(|mut value| {
+                Ok(Record {
+                    msg: serde_json::from_value(value["msg"].take())?,
+                    snapshot: value["snapshot"].as_str().map(ToString::to_string),
+                })
+            })
+            .collect::<Result<VecDeque<_>, serde_json::Error>>()?;
+        let mut history = self.history.borrow_mut();
+        history.base = Some(fresh);
+        history.position = records.len();
+        history.records = records;
+        Ok(())
+    }
+}
+
+/// the recorder stored in the program, which can only be created when the msgs can be cloned
+pub(crate) trait Recording<APP>
+where
+    APP: Application,
+{
+    /// called with the msg before it is passed to `update`
+    fn record_msg(&self, msg: &APP::MSG);
+
+    /// called with the application after it is updated
+    fn record_snapshot(&self, app: &APP);
+}
+
+impl<APP> Recording<APP> for Recorder<APP>
+where
+    APP: Application,
+    APP::MSG: Clone,
+{
+    fn record_msg(&self, msg: &APP::MSG) {
+        let mut history = self.history.borrow_mut();
+        // the records after the current position are replaced when travelled back in time
+        let position = history.position;
+        history.records.truncate(position);
+        history.records.push_back(Record {
+            msg: msg.clone(),
+            snapshot: None,
+        });
+        if history.records.len() > history.capacity {
+            if let Some(dropped) = history.records.pop_front() {
+                if let Some(base) = history.base.as_mut() {
+                    let _cmd = base.update(dropped.msg);
+                }
+            }
+        }
+        history.position = history.records.len();
+    }
+
+    fn record_snapshot(&self, app: &APP) {
+        let mut history = self.history.borrow_mut();
+        let Some(snapshot) = history.snapshot.as_ref().map(|snapshot| snapshot(app)) else {
+            return;
+        };
+        if let Some(record) = history.records.back_mut() {
+            record.snapshot = Some(snapshot);
+        }
+    }
+}
@@ -0,0 +1,444 @@
+//! Map the urls of the app into a route and back, and navigate in between routes
+//! using the history api of the browser.
+//!
+//! The matching of the urls is pure rust, so the same routes can be used in the server
+//! to match the path of the requested page.
+//!
+//! # Example
+//! ```rust
+//! use sauron::dom::router::{Route, Router};
+//!
+//! #[derive(Debug, PartialEq)]
+//! enum Page {
+//!     Home,
+//!     User(u32),
+//!     Search(String),
+//! }
+//!
+//! impl Route for Page {
+//!     fn from_url(url: &str) -> Option<Self> {
+//!         Router::new()
+//!             .route("/", |_| Some(Page::Home))
+//!             .route("/users/:id", |params| Some(Page::User(params.parse("id")?)))
+//!             .route("/search", |params| {
+//!                 Some(Page::Search(params.query("q").unwrap_or_default().to_string()))
+//!             })
+//!             .recognize(url)
+//!     }
+//!
+//!     fn to_url(&self) -> String {
+//!         match self {
+//!             Page::Home => "/".to_string(),
+//!             Page::User(id) => format!("/users/{id}"),
+//!             Page::Search(q) => format!("/search?q={}", sauron::dom::router::encode(q)),
+//!         }
+//!     }
+//! }
+//!
+//! assert_eq!(Page::from_url("/users/42"), Some(Page::User(42)));
+//! assert_eq!(Page::from_url("/search?q=rust%20wasm"), Some(Page::Search("rust wasm".into())));
+//! assert_eq!(Page::from_url("/users/x"), None);
+//! ```
+use std::str::FromStr;
+
+#[cfg(feature = "with-dom")]
+use crate::dom::{dom_node::intern, history, window, Cmd};
+#[cfg(feature = "with-dom")]
+use futures::channel::mpsc;
+#[cfg(feature = "with-dom")]
+use std::{cell::RefCell, rc::Rc};
+#[cfg(feature = "with-dom")]
+use wasm_bindgen::{closure::Closure, JsCast, JsValue};
+
+#[cfg(feature = "with-dom")]
+thread_local! {
+    /// the listeners of [`on_route_change`], which are notified when the url is changed
+    /// with [`navigate`] or [`redirect`], since `pushState` and `replaceState` don't trigger a
+    /// popstate
+    static ROUTE_LISTENERS: RefCell<Vec<Rc<dyn Fn()>>> = RefCell::new(vec![]);
+}
+
+/// a route of the app, which can be created from a url and converted back into a url
+pub trait Route: Sized {
+    /// the route of this url which has the path, and optionally the query and fragment,
+    /// None if the url does not match any route
+    fn from_url(url: &str) -> Option<Self>;
+
+    /// the url of this route, used in the links and when navigating to this route
+    fn to_url(&self) -> String;
+}
+
+/// the parts of a url which are matched against the routes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Url {
+    /// the decoded segments of the path, ie: `["users", "42"]` for `/users/42`
+    pub segments: Vec<String>,
+    /// the decoded query parameters in the order they appear in the url
+    pub query: Vec<(String, String)>,
+    /// the decoded fragment of the url without the `#`
+    pub fragment: Option<String>,
+}
+
+impl Url {
+    /// parse the path, query and fragment of a url.
+    /// The scheme and host if any are ignored, so both `/users/42` and
+    /// `https://example.com/users/42` have the same segments
+    pub fn parse(url: &str) -> Self {
+        let (url, fragment) = match url.split_once('#') {
+            Some((url, fragment)) => (url, Some(decode(fragment))),
+            None => (url, None),
+        };
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        let path = match path.split_once("://") {
+            Some((_scheme, rest)) => rest.find('/').map(|i| &rest[i..]).unwrap_or(""),
+            None => path,
+        };
+        Self {
+            segments: path
+                .split('/')
+                .filter(|segment| !segment.is_empty())
+                .map(decode)
+                .collect(),
+            query: query
+                .split('&')
+                .filter(|pair| !pair.is_empty())
+                .map(|pair| {
+                    let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                    (decode_query(key), decode_query(value))
+                })
+                .collect(),
+            fragment,
+        }
+    }
+
+    /// the first value of the query parameter
+    pub fn query(&self, key: &str) -> Option<&str> {
+        self.query
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    }
+}
+
+/// the values captured by the parameters of a matched pattern, along with the url
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Params {
+    url: Url,
+    captures: Vec<(String, String)>,
+}
+
+impl Params {
+    /// the value of the path parameter, ie: `id` in `/users/:id`
+    /// or `rest` in `/files/*rest`
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.captures
+            .iter()
+            .find(|(n, _)| n == name)
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// parse the value of the path parameter, None if it is missing or can not be parsed
+    pub fn parse<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.get(name)?.parse().ok()
+    }
+
+    /// the first value of the query parameter
+    pub fn query(&self, key: &str) -> Option<&str> {
+        self.url.query(key)
+    }
+
+    /// the url which was matched
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+}
+
+/// a segment of a route pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// matches this exact segment
+    Literal(String),
+    /// `:name` matches any single segment
+    Param(String),
+    /// `*name` matches the rest of the segments, joined with `/`
+    Rest(String),
+}
+
+/// converts the parameters of a matched pattern into a route
+type ToRoute<R> = Box<dyn Fn(&Params) -> Option<R>>;
+
+/// a table of url patterns, matched in the order they are added
+pub struct Router<R> {
+    routes: Vec<(Vec<Segment>, ToRoute<R>)>,
+}
+
+impl<R> Default for Router<R> {
+    fn default() -> Self {
+        Self { routes: vec![] }
+    }
+}
+
+impl<R> Router<R> {
+    /// create an empty router
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// add a route with a pattern such as `/users/:id` or `/files/*path`.
+    /// The route is skipped if `to_route` returns None, ie: when a parameter can not be parsed,
+    /// and the next routes are tried
+    pub fn route<F>(mut self, pattern: &str, to_route: F) -> Self
+    where
+        F: Fn(&Params) -> Option<R> + 'static,
+    {
+        let segments = pattern
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| {
+                if let Some(name) = segment.strip_prefix(':') {
+                    Segment::Param(name.to_string())
+                } else if let Some(name) = segment.strip_prefix('*') {
+                    Segment::Rest(name.to_string())
+                } else {
+                    Segment::Literal(segment.to_string())
+                }
+            })
+            .collect();
+        self.routes.push((segments, Box::new(to_route)));
+        self
+    }
+
+    /// the route of the first pattern which matches the url
+    pub fn recognize(&self, url: &str) -> Option<R> {
+        let url = Url::parse(url);
+        self.routes.iter().find_map(|(pattern, to_route)| {
+            let captures = captures(pattern, &url.segments)?;
+            to_route(&Params {
+                url: url.clone(),
+                captures,
+            })
+        })
+    }
+}
+
+/// the values of the parameters if the segments matches the pattern
+fn captures(pattern: &[Segment], segments: &[String]) -> Option<Vec<(String, String)>> {
+    let mut captures = vec![];
+    let mut segments = segments.iter();
+    for (i, part) in pattern.iter().enumerate() {
+        match part {
+            Segment::Rest(name) if i == pattern.len() - 1 => {
+                let rest: Vec<&str> = segments.by_ref().map(|s| s.as_str()).collect();
+                captures.push((name.clone(), rest.join("/")));
+            }
+            Segment::Literal(literal) => {
+                if segments.next()? != literal {
+                    return None;
+                }
+            }
+            Segment::Param(name) | Segment::Rest(name) => {
+                captures.push((name.clone(), segments.next()?.clone()));
+            }
+        }
+    }
+    segments.next().is_none().then_some(captures)
+}
+
+/// percent encode the text so it can be used as a segment or a query value of a url
+pub fn encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// decode the percent encoded text, invalid escapes are kept as is
+fn decode(text: &str) -> String {
+    let bytes = text.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                decoded.push(byte);
+                i += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// in the query, a `+` is a space
+fn decode_query(text: &str) -> String {
+    decode(&text.replace('+', " "))
+}
+
+/// the route of the current location of the browser
+#[cfg(feature = "with-dom")]
+pub fn current_route<R: Route>() -> Option<R> {
+    R::from_url(&current_url())
+}
+
+/// the path, query and fragment of the current location
+#[cfg(feature = "with-dom")]
+fn current_url() -> String {
+    let location = window().location();
+    let path = location.pathname().unwrap_or_default();
+    let search = location.search().unwrap_or_default();
+    let hash = location.hash().unwrap_or_default();
+    format!("{path}{search}{hash}")
+}
+
+/// navigate to the route, adding an entry in the history once the Cmd is executed.
+/// The message of [`on_route_change`] is emitted with the new route
+#[cfg(feature = "with-dom")]
+pub fn navigate<R: Route, MSG>(route: &R) -> Cmd<MSG>
+where
+    MSG: 'static,
+{
+    let url = route.to_url();
+    Cmd::from_effect(move || {
+        history()
+            .push_state_with_url(&JsValue::NULL, "", Some(&url))
+            .expect("must push state");
+        notify_route_change();
+    })
+}
+
+/// navigate to the route, replacing the current entry in the history once the Cmd is executed.
+/// The message of [`on_route_change`] is emitted with the new route
+#[cfg(feature = "with-dom")]
+pub fn redirect<R: Route, MSG>(route: &R) -> Cmd<MSG>
+where
+    MSG: 'static,
+{
+    let url = route.to_url();
+    Cmd::from_effect(move || {
+        history()
+            .replace_state_with_url(&JsValue::NULL, "", Some(&url))
+            .expect("must replace state");
+        notify_route_change();
+    })
+}
+
+/// notify the listeners of [`on_route_change`] that the url is changed
+#[cfg(feature = "with-dom")]
+fn notify_route_change() {
+    // cloned, so the listeners can be added or removed while they are notified
+    let listeners = ROUTE_LISTENERS.with(|listeners| listeners.borrow().clone());
+    for listener in listeners {
+        listener();
+    }
+}
+
+/// Create a recurring Cmd which emits the message of the new route
+/// everytime the route changes, when:
+/// - the user goes back or forward in the history
+/// - [`navigate`] or [`redirect`] is called
+/// - a link to a url which matches a route is clicked, the page is not reloaded
+///   and the url is pushed to the history instead.
+///   Links with a `target`, a `download` attribute or clicked with a modifier key are left
+///   to the browser.
+///
+/// Urls which do not match a route are passed to `cb` as None, ie: to show a not found page
+#[cfg(feature = "with-dom")]
+pub fn on_route_change<R, F, MSG>(cb: F) -> Cmd<MSG>
+where
+    R: Route,
+    F: FnMut(Option<R>) -> MSG + 'static,
+    MSG: 'static,
+{
+    let (tx, rx) = mpsc::unbounded();
+    let cb = RefCell::new(cb);
+    let emit = Rc::new(move |route: Option<R>| {
+        let msg = (cb.borrow_mut())(route);
+        tx.unbounded_send(msg).expect("send");
+    });
+    let emit_event = Rc::clone(&emit);
+    let closure_cb: Closure<dyn FnMut(web_sys::Event)> =
+        Closure::new(move |event: web_sys::Event| {
+            if event.type_() == "click" {
+                let Some(href) = internal_link(&event) else {
+                    return;
+                };
+                if R::from_url(&href).is_none() {
+                    return;
+                }
+                event.prevent_default();
+                history()
+                    .push_state_with_url(&JsValue::NULL, "", Some(&href))
+                    .expect("must push state");
+                // the other listeners skip the click since its default is prevented,
+                // they are notified of the new route along with this one
+                notify_route_change();
+            } else {
+                emit_event(R::from_url(&current_url()));
+            }
+        });
+    let listener: Rc<dyn Fn()> = Rc::new(move || emit(R::from_url(&current_url())));
+    ROUTE_LISTENERS.with(|listeners| listeners.borrow_mut().push(Rc::clone(&listener)));
+    const EVENT_NAMES: [&str; 2] = ["popstate", "click"];
+    let callback: js_sys::Function = closure_cb
+        .as_ref()
+        .unchecked_ref::<js_sys::Function>()
+        .clone();
+    for event_name in EVENT_NAMES {
+        window()
+            .add_event_listener_with_callback(intern(event_name), &callback)
+            .expect("add event callback");
+    }
+    let mut cmd = Cmd::recurring(rx, closure_cb);
+    cmd.set_teardown(move || {
+        for event_name in EVENT_NAMES {
+            window()
+                .remove_event_listener_with_callback(intern(event_name), &callback)
+                .expect("remove event callback");
+        }
+        ROUTE_LISTENERS.with(|listeners| {
+            listeners
+                .borrow_mut()
+                .retain(|registered| !Rc::ptr_eq(registered, &listener))
+        });
+    });
+    cmd
+}
+
+/// the href of the clicked link if it is a plain left click on a link to the same origin
+#[cfg(feature = "with-dom")]
+fn internal_link(event: &web_sys::Event) -> Option<String> {
+    let mouse_event: &web_sys::MouseEvent = event.dyn_ref()?;
+    if event.default_prevented()
+        || mouse_event.button() != 0
+        || mouse_event.ctrl_key()
+        || mouse_event.meta_key()
+        || mouse_event.shift_key()
+        || mouse_event.alt_key()
+    {
+        return None;
+    }
+    let target: web_sys::Element = event.target()?.dyn_into().ok()?;
+    let link = target.closest("a[href]").ok()??;
+    let opens_elsewhere = link
+        .get_attribute("target")
+        .is_some_and(|target| target != "_self");
+    if opens_elsewhere || link.has_attribute("download") {
+        return None;
+    }
+    let href = link.get_attribute("href")?;
+    // links to other sites and protocol relative urls are not handled
+    (href.starts_with('/') && !href.starts_with("//")).then_some(href)
+}
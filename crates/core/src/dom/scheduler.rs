@@ -0,0 +1,106 @@
+use std::rc::Rc;
+
+/// the work of a program which is scheduled, that is applying the dispatched msgs
+/// and rendering the view
+pub type Task = Box<dyn FnOnce()>;
+
+/// specify when the dispatched msgs are passed to `update` and the view is rendered
+#[derive(Clone)]
+pub enum Schedule {
+    /// right after the current task, in a microtask
+    Immediate,
+    /// in the next animation frame, so the msgs dispatched within a frame are batched into
+    /// a single render
+    AnimationFrame,
+    /// when the browser is idle, the msgs which are not applied before the idle deadline
+    /// are continued in the next idle period
+    Idle,
+    /// only when the program is flushed, ie: to step through the updates in tests
+    Manual,
+    /// the function is called with the work to be done, and decides when to run it
+    Custom(Rc<dyn Fn(Task)>),
+}
+
+/// specify how the updates of a program are scheduled
+#[derive(Clone)]
+pub struct Scheduler {
+    /// when the dispatched msgs are applied
+    pub schedule: Schedule,
+    /// the minimum time in ms between 2 renders of the view,
+    /// the render which comes too soon is deferred until the frame budget has elapsed
+    pub frame_budget: Option<f64>,
+}
+
+impl Scheduler {
+    /// the frame budget of 60 frames per second
+    pub const FRAME_BUDGET_60FPS: f64 = 16.0;
+
+    /// scheduler with the specified schedule and frame budget
+    pub fn new(schedule: Schedule, frame_budget: Option<f64>) -> Self {
+        Self {
+            schedule,
+            frame_budget,
+        }
+    }
+
+    /// apply the msgs right after the current task, rendering at most 60 frames per second
+    pub fn immediate() -> Self {
+        Self::new(Schedule::Immediate, Some(Self::FRAME_BUDGET_60FPS))
+    }
+
+    /// apply the msgs in the next animation frame
+    pub fn animation_frame() -> Self {
+        Self::new(Schedule::AnimationFrame, None)
+    }
+
+    /// apply the msgs when the browser is idle, rendering at most 60 frames per second
+    pub fn idle() -> Self {
+        Self::new(Schedule::Idle, Some(Self::FRAME_BUDGET_60FPS))
+    }
+
+    /// apply the msgs only when the program is flushed, without a frame budget
+    pub fn manual() -> Self {
+        Self::new(Schedule::Manual, None)
+    }
+
+    /// the function is called with the work to be done when msgs are dispatched
+    pub fn custom(run: impl Fn(Task) + 'static) -> Self {
+        Self::new(Schedule::Custom(Rc::new(run)), None)
+    }
+
+    /// set the minimum time in ms between 2 renders of the view
+    pub fn with_frame_budget(mut self, frame_budget: f64) -> Self {
+        self.frame_budget = Some(frame_budget);
+        self
+    }
+
+    /// render the view after each update, no matter how soon
+    pub fn without_frame_budget(mut self) -> Self {
+        self.frame_budget = None;
+        self
+    }
+}
+
+impl Default for Scheduler {
+    /// immediate unless the `with-ric` or `with-raf` feature is enabled
+    fn default() -> Self {
+        if cfg!(feature = "with-ric") {
+            Self::idle()
+        } else if cfg!(feature = "with-raf") {
+            Self::animation_frame()
+        } else {
+            Self::immediate()
+        }
+    }
+}
+
+/// the scheduler of a program along with the work it has scheduled
+#[derive(Default)]
+pub(crate) struct Scheduling {
+    pub(crate) scheduler: Scheduler,
+    /// a dispatch is scheduled and has not run yet,
+    /// the msgs dispatched meanwhile are applied in that dispatch
+    pub(crate) dispatch_scheduled: bool,
+    /// a render is deferred until the frame budget has elapsed
+    pub(crate) render_deferred: bool,
+}
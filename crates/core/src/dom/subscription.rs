@@ -0,0 +1,304 @@
+//! Subscriptions are the global events and timers an application listens to,
+//! such as the resize of the window or an interval.
+//!
+//! Unlike the recurring `Cmd` from `Window::on_resize`, which listens for as long as the page
+//! lives, the subscriptions of an application are evaluated again after each update.
+//! The listeners of the subscriptions which are no longer returned are removed
+//! and the listeners of the new subscriptions are added.
+use crate::dom::{document, dom_node::intern, util, window};
+use futures::channel::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use std::{cell::RefCell, collections::BTreeMap, rc::Rc};
+use wasm_bindgen::{closure::Closure, JsCast};
+
+/// converts the event into the msg of the application
+type ToMsg<MSG> = Rc<dyn Fn(web_sys::Event) -> MSG>;
+
+/// where the events of a subscription comes from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Source {
+    /// an event listener on the window
+    Window(&'static str),
+    /// an event listener on the document
+    Document(&'static str),
+    /// an interval with this duration in milliseconds
+    Interval(i32),
+}
+
+struct Entry<MSG> {
+    source: Source,
+    to_msg: ToMsg<MSG>,
+}
+
+/// A set of subscriptions, returned by `Application::subscriptions`
+/// # Example
+/// ```rust,ignore
+/// fn subscriptions(&self) -> Subscription<Msg> {
+///     if self.is_dragging {
+///         Subscription::batch([
+///             Subscription::on_mousemove(|e| Msg::Drag(e.client_x(), e.client_y())),
+///             Subscription::on_mouseup(|_| Msg::EndDrag),
+///         ])
+///     } else {
+///         Subscription::none()
+///     }
+/// }
+/// ```
+pub struct Subscription<MSG> {
+    entries: Vec<Entry<MSG>>,
+}
+
+impl<MSG> Subscription<MSG>
+where
+    MSG: 'static,
+{
+    /// no subscriptions
+    pub fn none() -> Self {
+        Self { entries: vec![] }
+    }
+
+    /// combine multiple subscriptions into one
+    pub fn batch(subscriptions: impl IntoIterator<Item = Self>) -> Self {
+        Self {
+            entries: subscriptions
+                .into_iter()
+                .flat_map(|subscription| subscription.entries)
+                .collect(),
+        }
+    }
+
+    /// listen to this event in the window
+    pub fn on_window<F>(event_name: &'static str, f: F) -> Self
+    where
+        F: Fn(web_sys::Event) -> MSG + 'static,
+    {
+        Self::single(Source::Window(event_name), f)
+    }
+
+    /// listen to this event in the document
+    pub fn on_document<F>(event_name: &'static str, f: F) -> Self
+    where
+        F: Fn(web_sys::Event) -> MSG + 'static,
+    {
+        Self::single(Source::Document(event_name), f)
+    }
+
+    /// emit a msg at every `interval_ms`
+    pub fn every<F>(interval_ms: i32, f: F) -> Self
+    where
+        F: Fn() -> MSG + 'static,
+    {
+        Self::single(Source::Interval(interval_ms), move |_| f())
+    }
+
+    /// emits the width and height of the window everytime it is resized
+    pub fn on_resize<F>(f: F) -> Self
+    where
+        F: Fn(i32, i32) -> MSG + 'static,
+    {
+        Self::on_window("resize", move |_| {
+            let (w, h) = util::get_window_size();
+            f(w, h)
+        })
+    }
+
+    /// listen to the mouse moves in the window
+    pub fn on_mousemove<F>(f: F) -> Self
+    where
+        F: Fn(web_sys::MouseEvent) -> MSG + 'static,
+    {
+        Self::on_window("mousemove", move |event| {
+            f(event.dyn_into().expect("must be mouse event"))
+        })
+    }
+
+    /// listen to the mouse up in the window
+    pub fn on_mouseup<F>(f: F) -> Self
+    where
+        F: Fn(web_sys::MouseEvent) -> MSG + 'static,
+    {
+        Self::on_window("mouseup", move |event| {
+            f(event.dyn_into().expect("must be mouse event"))
+        })
+    }
+
+    /// listen to the key down in the window
+    pub fn on_keydown<F>(f: F) -> Self
+    where
+        F: Fn(web_sys::KeyboardEvent) -> MSG + 'static,
+    {
+        Self::on_window("keydown", move |event| {
+            f(event.dyn_into().expect("must be key event"))
+        })
+    }
+
+    /// listen to the key up in the window
+    pub fn on_keyup<F>(f: F) -> Self
+    where
+        F: Fn(web_sys::KeyboardEvent) -> MSG + 'static,
+    {
+        Self::on_window("keyup", move |event| {
+            f(event.dyn_into().expect("must be key event"))
+        })
+    }
+
+    /// listen to the changes of the text selection in the document
+    pub fn on_selectionchange<F>(f: F) -> Self
+    where
+        F: Fn(Option<web_sys::Selection>) -> MSG + 'static,
+    {
+        Self::on_document("selectionchange", move |_| {
+            f(document().get_selection().ok().flatten())
+        })
+    }
+
+    /// map the msg of this subscription such that `Subscription<MSG>` becomes
+    /// `Subscription<MSG2>`
+    pub fn map_msg<F, MSG2>(self, f: F) -> Subscription<MSG2>
+    where
+        F: Fn(MSG) -> MSG2 + Clone + 'static,
+        MSG2: 'static,
+    {
+        Subscription {
+            entries: self
+                .entries
+                .into_iter()
+                .map(|Entry { source, to_msg }| {
+                    let f = f.clone();
+                    let to_msg: ToMsg<MSG2> = Rc::new(move |event| f(to_msg(event)));
+                    Entry { source, to_msg }
+                })
+                .collect(),
+        }
+    }
+
+    fn single<F>(source: Source, f: F) -> Self
+    where
+        F: Fn(web_sys::Event) -> MSG + 'static,
+    {
+        Self {
+            entries: vec![Entry {
+                source,
+                to_msg: Rc::new(f),
+            }],
+        }
+    }
+}
+
+/// an event listener or interval which is currently registered
+struct Listener<MSG> {
+    source: Source,
+    /// swapped with the function of the latest subscription, since it may capture a
+    /// different state of the application
+    to_msg: Rc<RefCell<ToMsg<MSG>>>,
+    closure: Closure<dyn FnMut(web_sys::Event)>,
+    interval_handle: Option<i32>,
+}
+
+impl<MSG> Listener<MSG>
+where
+    MSG: 'static,
+{
+    fn start(source: Source, to_msg: ToMsg<MSG>, sender: UnboundedSender<MSG>) -> Self {
+        let to_msg = Rc::new(RefCell::new(to_msg));
+        let closure: Closure<dyn FnMut(web_sys::Event)> = {
+            let to_msg = Rc::clone(&to_msg);
+            Closure::new(move |event: web_sys::Event| {
+                let msg = (to_msg.borrow())(event);
+                // the program is no longer running when the receiver is dropped
+                let _ = sender.unbounded_send(msg);
+            })
+        };
+        let callback = closure.as_ref().unchecked_ref();
+        let mut interval_handle = None;
+        match source {
+            Source::Window(event_name) => window()
+                .add_event_listener_with_callback(intern(event_name), callback)
+                .expect("add event callback"),
+            Source::Document(event_name) => document()
+                .add_event_listener_with_callback(intern(event_name), callback)
+                .expect("add event callback"),
+            Source::Interval(interval_ms) => {
+                let handle = window()
+                    .set_interval_with_callback_and_timeout_and_arguments_0(callback, interval_ms)
+                    .expect("Unable to start interval");
+                interval_handle = Some(handle);
+            }
+        }
+        Self {
+            source,
+            to_msg,
+            closure,
+            interval_handle,
+        }
+    }
+}
+
+impl<MSG> Drop for Listener<MSG> {
+    fn drop(&mut self) {
+        let callback = self.closure.as_ref().unchecked_ref();
+        match self.source {
+            Source::Window(event_name) => window()
+                .remove_event_listener_with_callback(intern(event_name), callback)
+                .expect("remove event callback"),
+            Source::Document(event_name) => document()
+                .remove_event_listener_with_callback(intern(event_name), callback)
+                .expect("remove event callback"),
+            Source::Interval(_) => {
+                if let Some(handle) = self.interval_handle {
+                    window().clear_interval_with_handle(handle);
+                }
+            }
+        }
+    }
+}
+
+/// the listeners of the subscriptions of a program.
+/// The msgs of the listeners are sent into a channel instead of being dispatched directly,
+/// so a listener is never removed while it is being called
+pub(crate) struct ActiveSubscriptions<MSG> {
+    /// keyed by the source and the nth time this source is used in the subscriptions
+    listeners: BTreeMap<(Source, usize), Listener<MSG>>,
+    sender: UnboundedSender<MSG>,
+    receiver: Option<UnboundedReceiver<MSG>>,
+}
+
+impl<MSG> ActiveSubscriptions<MSG>
+where
+    MSG: 'static,
+{
+    pub(crate) fn new() -> Self {
+        let (sender, receiver) = mpsc::unbounded();
+        Self {
+            listeners: BTreeMap::new(),
+            sender,
+            receiver: Some(receiver),
+        }
+    }
+
+    /// the receiver of the msgs emitted by the listeners, this can only be taken once
+    pub(crate) fn take_receiver(&mut self) -> Option<UnboundedReceiver<MSG>> {
+        self.receiver.take()
+    }
+
+    /// add the listeners of the new subscriptions and remove the listeners of the subscriptions
+    /// which are no longer used, the listeners of the same subscriptions are kept
+    pub(crate) fn sync(&mut self, subscription: Subscription<MSG>) {
+        let mut listeners = BTreeMap::new();
+        let mut occurrences: BTreeMap<Source, usize> = BTreeMap::new();
+        for Entry { source, to_msg } in subscription.entries {
+            let nth = occurrences.entry(source).or_default();
+            let key = (source, *nth);
+            *nth += 1;
+            let listener = match self.listeners.remove(&key) {
+                Some(listener) => {
+                    *listener.to_msg.borrow_mut() = to_msg;
+                    listener
+                }
+                None => Listener::start(source, to_msg, self.sender.clone()),
+            };
+            listeners.insert(key, listener);
+        }
+        // the remaining listeners are removed when dropped
+        self.listeners = listeners;
+    }
+}
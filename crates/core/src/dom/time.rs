@@ -20,12 +20,14 @@ impl Time {
             let msg = cb();
             tx.start_send(msg).unwrap();
         });
-        window()
+        let handle = window()
             .set_interval_with_callback_and_timeout_and_arguments_0(
                 closure_cb.as_ref().unchecked_ref(),
                 interval_ms,
             )
             .expect("Unable to start interval");
-        Cmd::recurring(rx, closure_cb)
+        let mut cmd = Cmd::recurring(rx, closure_cb);
+        cmd.set_teardown(move || window().clear_interval_with_handle(handle));
+        cmd
     }
 }
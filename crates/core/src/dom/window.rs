@@ -1,4 +1,4 @@
-use crate::dom::{dom_node::intern, util, window, Cmd};
+use crate::dom::{util, window, Cmd};
 use futures::channel::mpsc;
 use wasm_bindgen::{prelude::*, JsCast};
 use web_sys::MouseEvent;
@@ -22,14 +22,7 @@ impl Window {
                 let msg = cb(w, h);
                 tx.start_send(msg).expect("send");
             });
-        window()
-            .add_event_listener_with_callback(
-                intern("resize"),
-                resize_callback.as_ref().unchecked_ref(),
-            )
-            .expect("add event callback");
-
-        Cmd::recurring(rx, resize_callback)
+        Cmd::recurring_listener(&window(), "resize", rx, resize_callback)
     }
 
     ///
@@ -45,13 +38,7 @@ impl Window {
                 let msg = cb(mouse_event);
                 tx.start_send(msg).expect("send");
             });
-        window()
-            .add_event_listener_with_callback(
-                intern("mousemove"),
-                mousemove_cb.as_ref().unchecked_ref(),
-            )
-            .expect("add event callback");
-        Cmd::recurring(rx, mousemove_cb)
+        Cmd::recurring_listener(&window(), "mousemove", rx, mousemove_cb)
     }
 
     ///
@@ -67,13 +54,7 @@ impl Window {
                 let msg = cb(mouse_event);
                 tx.start_send(msg).expect("send");
             });
-        window()
-            .add_event_listener_with_callback(
-                intern("mouseup"),
-                mousemove_cb.as_ref().unchecked_ref(),
-            )
-            .expect("add event callback");
-        Cmd::recurring(rx, mousemove_cb)
+        Cmd::recurring_listener(&window(), "mouseup", rx, mousemove_cb)
     }
 
     ///
@@ -89,13 +70,7 @@ impl Window {
                 let msg = cb(mouse_event);
                 tx.start_send(msg).expect("send");
             });
-        window()
-            .add_event_listener_with_callback(
-                intern("mousedown"),
-                mousemove_cb.as_ref().unchecked_ref(),
-            )
-            .expect("add event callback");
-        Cmd::recurring(rx, mousemove_cb)
+        Cmd::recurring_listener(&window(), "mousedown", rx, mousemove_cb)
     }
 
     ///
@@ -111,13 +86,7 @@ impl Window {
                 let msg = cb(mouse_event);
                 tx.start_send(msg).expect("send");
             });
-        window()
-            .add_event_listener_with_callback(
-                intern("click"),
-                mousemove_cb.as_ref().unchecked_ref(),
-            )
-            .expect("add event callback");
-        Cmd::recurring(rx, mousemove_cb)
+        Cmd::recurring_listener(&window(), "click", rx, mousemove_cb)
     }
 
     ///
@@ -134,10 +103,7 @@ impl Window {
                 let msg = cb(key_event);
                 tx.start_send(msg).expect("send");
             });
-        window()
-            .add_event_listener_with_callback(intern("keyup"), closure_cb.as_ref().unchecked_ref())
-            .expect("add event callback");
-        Cmd::recurring(rx, closure_cb)
+        Cmd::recurring_listener(&window(), "keyup", rx, closure_cb)
     }
 
     ///
@@ -154,13 +120,7 @@ impl Window {
                 let msg = cb(key_event);
                 tx.start_send(msg).expect("send");
             });
-        window()
-            .add_event_listener_with_callback(
-                intern("keydown"),
-                closure_cb.as_ref().unchecked_ref(),
-            )
-            .expect("add event callback");
-        Cmd::recurring(rx, closure_cb)
+        Cmd::recurring_listener(&window(), "keydown", rx, closure_cb)
     }
 
     /// scroll the window to the top of the document
@@ -189,12 +149,6 @@ impl Window {
                 let msg = cb(popstate_event);
                 tx.start_send(msg).expect("send");
             });
-        window()
-            .add_event_listener_with_callback(
-                intern("popstate"),
-                closure_cb.as_ref().unchecked_ref(),
-            )
-            .expect("add event callback");
-        Cmd::recurring(rx, closure_cb)
+        Cmd::recurring_listener(&window(), "popstate", rx, closure_cb)
     }
 }
@@ -2,7 +2,7 @@
 use crate::vdom;
 use crate::vdom::Leaf;
 pub use crate::vdom::{element, element_ns};
-use crate::vdom::{Attribute, Node};
+use crate::vdom::{Attribute, Node, Tag, SEPARATOR};
 use std::borrow::Cow;
 pub use tags::{commons::*, self_closing::*, *};
 
@@ -71,7 +71,7 @@ where
 /// ```
 pub fn html_element<MSG>(
     namespace: Option<&'static str>,
-    tag: &'static str,
+    tag: impl Into<Tag>,
     attrs: impl IntoIterator<Item = Attribute<MSG>>,
     children: impl IntoIterator<Item = Node<MSG>>,
     self_closing: bool,
@@ -85,7 +85,7 @@ pub fn html_element<MSG>(
             //TODO: put this behind a flag: #auto-separator to automatically
             //add separator between text nodes
             if last.is_text() && child.is_text() {
-                corrected_children.push(comment("separator"));
+                corrected_children.push(comment(SEPARATOR));
             }
         }
         corrected_children.push(child);
@@ -16,7 +16,7 @@ use crate::{
 use once_cell::sync::Lazy;
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
-pub use style_lookup::match_property;
+pub use style_lookup::{match_property, property_value_kind, ValueKind};
 mod style_lookup;
 
 /// All of the svg tags
@@ -57,6 +57,15 @@ static ALL_ATTRS: Lazy<BTreeMap<&'static str, &'static str>> = Lazy::new(|| {
     )
 });
 
+/// All of the svg attributes, used to restore the case of camelCase svg attributes
+static ALL_SVG_ATTRS: Lazy<BTreeSet<&'static str>> = Lazy::new(|| {
+    SVG_ATTRS
+        .iter()
+        .copied()
+        .chain(SVG_ATTRS_SPECIAL.iter().map(|(_func, att)| *att))
+        .collect()
+});
+
 /// Find the namespace of this tag
 /// if the arg tag is an SVG tag, return the svg namespace
 /// html tags don't need to have namespace while svg does, otherwise it will not be properly
@@ -106,6 +115,22 @@ pub fn match_tag(tag: &str) -> Option<&'static str> {
         .map(|t| **t)
 }
 
+/// return the matching svg tag in its proper case, ie: `lineargradient` matches `linearGradient`
+pub fn match_svg_tag(tag: &str) -> Option<&'static str> {
+    ALL_SVG_TAGS
+        .iter()
+        .find(|t| t.eq_ignore_ascii_case(tag))
+        .map(|t| **t)
+}
+
+/// return the matching svg attribute in its proper case, ie: `viewbox` matches `viewBox`
+pub fn match_svg_attribute(att: &str) -> Option<&'static str> {
+    ALL_SVG_ATTRS
+        .iter()
+        .find(|a| a.eq_ignore_ascii_case(att))
+        .copied()
+}
+
 /// Returns true if this html tag is self closing
 #[inline]
 pub fn is_self_closing(tag: &str) -> bool {
@@ -16,6 +16,25 @@ pub fn match_property(property: &str) -> Option<&str> {
     }
 }
 
+/// the kind of value expected by a css property
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValueKind {
+    /// a length or a percentage, ie: `10px`, `50%`
+    Length,
+    /// a color, ie: `rgb(0, 0, 255)`
+    Color,
+    /// an angle, ie: `90deg`
+    Angle,
+    /// a time, ie: `200ms`
+    Time,
+}
+
+/// return the kind of value expected by this well-known css property,
+/// the property name is the corrected name, ie: `background-color`
+pub fn property_value_kind(property: &str) -> Option<ValueKind> {
+    PROPERTY_VALUE_KINDS.get(property).copied()
+}
+
 static ALL_STYLES: Lazy<BTreeSet<&'static str>> = Lazy::new(|| {
     BTreeSet::from_iter(
         HTML_STYLES
@@ -737,3 +756,74 @@ pub(super) static SVG_STYLES: phf::Map<&'static str, &'static str> = phf_map! {
         "z" => "z",
         "zoom_and_pan" => "zoomAndPan",
 };
+
+/// the kind of value of the well-known css properties, used to type check the values
+/// in `style!` and `jss!`
+static PROPERTY_VALUE_KINDS: phf::Map<&'static str, ValueKind> = phf_map! {
+    "width" => ValueKind::Length,
+    "height" => ValueKind::Length,
+    "min-width" => ValueKind::Length,
+    "min-height" => ValueKind::Length,
+    "max-width" => ValueKind::Length,
+    "max-height" => ValueKind::Length,
+    "top" => ValueKind::Length,
+    "right" => ValueKind::Length,
+    "bottom" => ValueKind::Length,
+    "left" => ValueKind::Length,
+    "inset" => ValueKind::Length,
+    "margin" => ValueKind::Length,
+    "margin-top" => ValueKind::Length,
+    "margin-right" => ValueKind::Length,
+    "margin-bottom" => ValueKind::Length,
+    "margin-left" => ValueKind::Length,
+    "padding" => ValueKind::Length,
+    "padding-top" => ValueKind::Length,
+    "padding-right" => ValueKind::Length,
+    "padding-bottom" => ValueKind::Length,
+    "padding-left" => ValueKind::Length,
+    "border-width" => ValueKind::Length,
+    "border-top-width" => ValueKind::Length,
+    "border-right-width" => ValueKind::Length,
+    "border-bottom-width" => ValueKind::Length,
+    "border-left-width" => ValueKind::Length,
+    "border-radius" => ValueKind::Length,
+    "border-top-left-radius" => ValueKind::Length,
+    "border-top-right-radius" => ValueKind::Length,
+    "border-bottom-left-radius" => ValueKind::Length,
+    "border-bottom-right-radius" => ValueKind::Length,
+    "outline-width" => ValueKind::Length,
+    "outline-offset" => ValueKind::Length,
+    "font-size" => ValueKind::Length,
+    "line-height" => ValueKind::Length,
+    "letter-spacing" => ValueKind::Length,
+    "word-spacing" => ValueKind::Length,
+    "text-indent" => ValueKind::Length,
+    "flex-basis" => ValueKind::Length,
+    "gap" => ValueKind::Length,
+    "row-gap" => ValueKind::Length,
+    "column-gap" => ValueKind::Length,
+    "column-width" => ValueKind::Length,
+    "stroke-width" => ValueKind::Length,
+    "color" => ValueKind::Color,
+    "background-color" => ValueKind::Color,
+    "border-color" => ValueKind::Color,
+    "border-top-color" => ValueKind::Color,
+    "border-right-color" => ValueKind::Color,
+    "border-bottom-color" => ValueKind::Color,
+    "border-left-color" => ValueKind::Color,
+    "outline-color" => ValueKind::Color,
+    "text-decoration-color" => ValueKind::Color,
+    "caret-color" => ValueKind::Color,
+    "accent-color" => ValueKind::Color,
+    "column-rule-color" => ValueKind::Color,
+    "fill" => ValueKind::Color,
+    "stroke" => ValueKind::Color,
+    "stop-color" => ValueKind::Color,
+    "flood-color" => ValueKind::Color,
+    "lighting-color" => ValueKind::Color,
+    "rotate" => ValueKind::Angle,
+    "transition-duration" => ValueKind::Time,
+    "transition-delay" => ValueKind::Time,
+    "animation-duration" => ValueKind::Time,
+    "animation-delay" => ValueKind::Time,
+};
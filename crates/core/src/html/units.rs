@@ -1,58 +1,37 @@
 //! provides function and macro for html units such as px, %, em, etc.
 
-use crate::vdom::Value;
 pub use fns::{rgb, rgba, rotate};
+pub use values::{
+    expect_angle, expect_color, expect_length, expect_time, Angle, AngleValue, Color, ColorValue,
+    Length, LengthValue, Numeric, Percentage, Time, TimeValue,
+};
 
 mod fns;
-
-#[inline]
-fn unit(unit_name: &str, v: impl Into<Value>) -> String {
-    let value: Value = v.into();
-    match value {
-        Value::Vec(values) => values
-            .into_iter()
-            .map(|v| format!("{}{}", Into::<Value>::into(v), unit_name))
-            .collect::<Vec<_>>()
-            .join(" "),
-        _ => {
-            format!("{}{}", value, unit_name)
-        }
-    }
-}
+mod values;
 
 macro_rules! declare_units{
-    (  $(
-            $(#[$attr:meta])*
-            $name:ident;
-        )*
-    ) => {
-        $(
-            $(#[$attr])*
-            ///
-            /// [MDN reference](https://developer.mozilla.org/en-US/docs/Learn/CSS/Building_blocks/Values_and_units)
-            pub fn $name(v: impl Into<Value>) -> String {
-                unit(stringify!($name), v)
-            }
-        )*
-    };
     (
+        $kind:ident:
         $(
             $(#[$attr:meta])*
-            $name:ident => $unit:tt;
-         )*
+            $name:ident $(=> $unit:tt)?;
+        )*
     ) => {
         $(
             $(#[$attr])*
             ///
             /// [MDN reference](https://developer.mozilla.org/en-US/docs/Learn/CSS/Building_blocks/Values_and_units)
-            pub fn $name(v: impl Into<Value>) -> String {
-                  unit($unit, v)
+            pub fn $name(v: impl Numeric) -> $kind {
+                $kind::new(v, declare_units!(@unit $name $($unit)?))
             }
         )*
-    }
+    };
+    (@unit $name:ident) => { stringify!($name) };
+    (@unit $name:ident $unit:tt) => { $unit };
 }
 
 declare_units! {
+    Length:
 
     /// pixels (1px = 1/96th of 1in)
     ///
@@ -167,6 +146,7 @@ declare_units! {
 }
 
 declare_units! {
+    Length:
     /// inches (1in = 96px = 2.54cm)
     /// Example:
     /// ```rust
@@ -175,6 +155,10 @@ declare_units! {
     /// assert_eq!("10in", r#in(10))
     /// ```
     r#in => "in";
+}
+
+declare_units! {
+    Percentage:
     /// percentage
     /// Example:
     /// ```rust
@@ -187,6 +171,7 @@ declare_units! {
 
 // angle units
 declare_units! {
+    Angle:
     /// Represent an angle in degrees
     /// https://developer.mozilla.org/en-US/docs/Web/CSS/angle
     deg;
@@ -203,6 +188,7 @@ declare_units! {
 
 // time units
 declare_units! {
+   Time:
    /// Represents a time in seconds.
    /// https://developer.mozilla.org/en-US/docs/Web/CSS/time
    s;
@@ -214,6 +200,7 @@ declare_units! {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::vdom::Value;
 
     #[test]
     fn test_units() {
@@ -225,4 +212,26 @@ mod tests {
         assert_eq!(r#in(2.5), "2.5in");
         assert_eq!(ch(1), "1ch");
     }
+
+    #[test]
+    fn test_typed_units() {
+        assert_eq!(px([1, 2]), "1px 2px");
+        assert_eq!(percent(50), "50%");
+        assert_eq!(deg(90), "90deg");
+        assert_eq!(ms(200), "200ms");
+        assert_eq!(rgb(0, 0, 255), "rgb(0, 0, 255)");
+        assert_eq!(expect_length(px(1)), Value::from("1px"));
+    }
+
+    #[test]
+    fn test_raw_string_values() {
+        let width = String::from("calc(100% - 10px)");
+        assert_eq!(
+            expect_length(width.as_str()),
+            Value::from("calc(100% - 10px)")
+        );
+        assert_eq!(expect_length(&width), Value::from("calc(100% - 10px)"));
+        assert_eq!(expect_color(String::from("red")), Value::from("red"));
+        assert_eq!(expect_length((px(1), "auto")), Value::from(("1px", "auto")));
+    }
 }
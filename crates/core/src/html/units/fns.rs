@@ -1,13 +1,14 @@
+use super::Color;
 use std::fmt::Display;
 
 /// the [rgb](https://developer.mozilla.org/en-US/docs/Web/CSS/color_value/rgb) css function
-pub fn rgb(r: impl Display, g: impl Display, b: impl Display) -> String {
-    format!("rgb({r}, {g}, {b})")
+pub fn rgb(r: impl Display, g: impl Display, b: impl Display) -> Color {
+    Color(format!("rgb({r}, {g}, {b})"))
 }
 
 /// the [rgba](https://developer.mozilla.org/en-US/docs/Web/CSS/color_value/rgb) css function
-pub fn rgba(r: impl Display, g: impl Display, b: impl Display, a: impl Display) -> String {
-    format!("rgba({r}, {g}, {b}, {a})")
+pub fn rgba(r: impl Display, g: impl Display, b: impl Display, a: impl Display) -> Color {
+    Color(format!("rgba({r}, {g}, {b}, {a})"))
 }
 
 /// rotate function
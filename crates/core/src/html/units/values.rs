@@ -0,0 +1,240 @@
+//! typed css values which are displayed as their css representation,
+//! so a length can not be passed to a property which expects a color.
+
+use crate::vdom::Value;
+use std::fmt;
+
+/// numbers which can be given a css unit, a number or an array of numbers
+pub trait Numeric: Into<Value> {}
+
+macro_rules! impl_numeric {
+    ($($ty:ty),*) => {
+        $(impl Numeric for $ty {})*
+    };
+}
+
+impl_numeric!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl<T, const N: usize> Numeric for [T; N] where T: Numeric + Clone {}
+
+macro_rules! declare_quantities {
+    ($(
+        $(#[$attr:meta])*
+        $name:ident;
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[derive(Debug, Clone, PartialEq)]
+            pub struct $name {
+                value: Value,
+                unit: &'static str,
+            }
+
+            impl $name {
+                pub(crate) fn new(value: impl Numeric, unit: &'static str) -> Self {
+                    Self {
+                        value: value.into(),
+                        unit,
+                    }
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    match &self.value {
+                        Value::Vec(values) => {
+                            for (i, value) in values.iter().enumerate() {
+                                if i > 0 {
+                                    write!(f, " ")?;
+                                }
+                                write!(f, "{}{}", value, self.unit)?;
+                            }
+                            Ok(())
+                        }
+                        value => write!(f, "{}{}", value, self.unit),
+                    }
+                }
+            }
+
+            impl From<$name> for Value {
+                fn from(v: $name) -> Self {
+                    Value::from(v.to_string())
+                }
+            }
+
+            impl PartialEq<&str> for $name {
+                fn eq(&self, other: &&str) -> bool {
+                    self.to_string().as_str() == *other
+                }
+            }
+
+            impl PartialEq<$name> for &str {
+                fn eq(&self, other: &$name) -> bool {
+                    *self == other.to_string().as_str()
+                }
+            }
+        )*
+    };
+}
+
+declare_quantities! {
+    /// a [length](https://developer.mozilla.org/en-US/docs/Web/CSS/length), ie: `10px`, `2em`
+    Length;
+    /// a [percentage](https://developer.mozilla.org/en-US/docs/Web/CSS/percentage), ie: `50%`
+    Percentage;
+    /// an [angle](https://developer.mozilla.org/en-US/docs/Web/CSS/angle), ie: `90deg`
+    Angle;
+    /// a [time](https://developer.mozilla.org/en-US/docs/Web/CSS/time), ie: `200ms`
+    Time;
+}
+
+/// a [color](https://developer.mozilla.org/en-US/docs/Web/CSS/color_value), ie: `rgb(0, 0, 255)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Color(pub(crate) String);
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl From<Color> for Value {
+    fn from(v: Color) -> Self {
+        Value::from(v.0)
+    }
+}
+
+impl PartialEq<&str> for Color {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<Color> for &str {
+    fn eq(&self, other: &Color) -> bool {
+        *self == other.0
+    }
+}
+
+macro_rules! declare_value_kinds {
+    ($(
+        $(#[$attr:meta])*
+        $kind:ident => $expect:ident : [$($ty:ty),*], $message:literal;
+    )*) => {
+        $(
+            $(#[$attr])*
+            #[diagnostic::on_unimplemented(message = $message)]
+            pub trait $kind {
+                /// convert into the value of the property
+                fn into_value(self) -> Value;
+            }
+
+            $(impl $kind for $ty {
+                fn into_value(self) -> Value {
+                    self.into()
+                }
+            })*
+
+            impl $kind for &str {
+                fn into_value(self) -> Value {
+                    Value::from(self.to_string())
+                }
+            }
+
+            impl $kind for String {
+                fn into_value(self) -> Value {
+                    Value::from(self)
+                }
+            }
+
+            impl $kind for &String {
+                fn into_value(self) -> Value {
+                    Value::from(self)
+                }
+            }
+
+            impl $kind for Value {
+                fn into_value(self) -> Value {
+                    self
+                }
+            }
+
+            /// used in the `style!` and `jss!` macro to check the value of a property
+            #[doc(hidden)]
+            pub fn $expect(value: impl $kind) -> Value {
+                value.into_value()
+            }
+        )*
+    };
+}
+
+declare_value_kinds! {
+    /// values accepted by properties which expect a length, such as `width` and `margin`.
+    /// Raw strings are accepted as is.
+    LengthValue => expect_length: [
+        Length, Percentage, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+    ], "`{Self}` is not a css length, use a length such as `px(10)`, `percent(50)` or a raw string";
+    /// values accepted by properties which expect a color, such as `color` and `background_color`.
+    /// Raw strings are accepted as is.
+    ColorValue => expect_color: [Color],
+        "`{Self}` is not a css color, use a color such as `rgb(0, 0, 255)` or a raw string";
+    /// values accepted by properties which expect an angle, such as `rotate`.
+    /// Raw strings are accepted as is.
+    AngleValue => expect_angle: [Angle],
+        "`{Self}` is not a css angle, use an angle such as `deg(90)` or a raw string";
+    /// values accepted by properties which expect a time, such as `transition_duration`.
+    /// Raw strings are accepted as is.
+    TimeValue => expect_time: [Time],
+        "`{Self}` is not a css time, use a time such as `ms(200)` or a raw string";
+}
+
+impl<T, const N: usize> LengthValue for [T; N]
+where
+    T: LengthValue,
+{
+    fn into_value(self) -> Value {
+        Value::Vec(self.into_iter().map(LengthValue::into_value).collect())
+    }
+}
+
+impl<V0, V1> LengthValue for (V0, V1)
+where
+    V0: LengthValue,
+    V1: LengthValue,
+{
+    fn into_value(self) -> Value {
+        Value::Vec(vec![self.0.into_value(), self.1.into_value()])
+    }
+}
+
+impl<V0, V1, V2> LengthValue for (V0, V1, V2)
+where
+    V0: LengthValue,
+    V1: LengthValue,
+    V2: LengthValue,
+{
+    fn into_value(self) -> Value {
+        Value::Vec(vec![
+            self.0.into_value(),
+            self.1.into_value(),
+            self.2.into_value(),
+        ])
+    }
+}
+
+impl<V0, V1, V2, V3> LengthValue for (V0, V1, V2, V3)
+where
+    V0: LengthValue,
+    V1: LengthValue,
+    V2: LengthValue,
+    V3: LengthValue,
+{
+    fn into_value(self) -> Value {
+        Value::Vec(vec![
+            self.0.into_value(),
+            self.1.into_value(),
+            self.2.into_value(),
+            self.3.into_value(),
+        ])
+    }
+}
@@ -51,8 +51,8 @@ pub mod prelude {
         pub use crate::html::events::*;
         pub use crate::dom::{Application, SkipDiff, skip_if, events, Program, document, Document, now, window, Window, Dispatch,
             AnimationFrameHandle, Component, StatefulComponent, Effects, Measurements, MountAction,
-            MountTarget, Cmd, TimeoutCallbackHandle, DomAttrValue,
-            stateful_component, Time,
+            MountTarget, Cmd, CmdHandle, TimeoutCallbackHandle, DomAttrValue,
+            stateful_component, Time, Subscription,
         };
     }}
 }
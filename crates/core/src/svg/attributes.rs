@@ -5,7 +5,8 @@ use crate::vdom::{attr, attr_ns};
 pub use commons::*;
 pub use special::*;
 
-pub(crate) const XLINK_NAMESPACE: &str = "http://www.w3.org/1999/xlink";
+/// xlink namespace const, used in attributes such as `xlink:href`
+pub const XLINK_NAMESPACE: &str = "http://www.w3.org/1999/xlink";
 
 macro_rules! declare_xlink_attributes {
     ( $(
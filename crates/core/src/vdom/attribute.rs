@@ -4,6 +4,7 @@ use crate::vdom::ComponentEventCallback;
 use crate::vdom::EventCallback;
 use derive_where::derive_where;
 use indexmap::IndexMap;
+use std::borrow::Cow;
 
 pub use attribute_value::AttributeValue;
 pub use callback::Callback;
@@ -20,10 +21,10 @@ mod value;
 pub type Namespace = &'static str;
 
 /// The type of the Tag
-pub type Tag = &'static str;
+pub type Tag = Cow<'static, str>;
 
 /// The type of Attribute Name
-pub type AttributeName = &'static str;
+pub type AttributeName = Cow<'static, str>;
 
 /// These are the plain attributes of an element
 #[derive_where(Clone, Debug, PartialEq, Eq)]
@@ -55,11 +56,11 @@ impl<MSG> Attribute<MSG> {
     /// create a plain attribute with namespace
     pub fn new(
         namespace: Option<Namespace>,
-        name: AttributeName,
+        name: impl Into<AttributeName>,
         value: AttributeValue<MSG>,
     ) -> Self {
         Attribute {
-            name,
+            name: name.into(),
             value: vec![value],
             namespace,
         }
@@ -68,11 +69,11 @@ impl<MSG> Attribute<MSG> {
     /// create from multiple values
     pub fn with_multiple_values(
         namespace: Option<Namespace>,
-        name: AttributeName,
+        name: impl Into<AttributeName>,
         value: impl IntoIterator<Item = AttributeValue<MSG>>,
     ) -> Self {
         Attribute {
-            name,
+            name: name.into(),
             value: value.into_iter().collect(),
             namespace,
         }
@@ -162,7 +163,7 @@ impl<MSG> Attribute<MSG> {
                         &att.name,
                         Attribute {
                             namespace: att.namespace,
-                            name: att.name,
+                            name: att.name.clone(),
                             value: att.value.clone(),
                         },
                     );
@@ -197,7 +198,10 @@ impl<MSG> Attribute<MSG> {
 /// let class: Attribute<()> = attr("class", "container");
 /// ```
 #[inline]
-pub fn attr<MSG>(name: AttributeName, value: impl Into<AttributeValue<MSG>>) -> Attribute<MSG> {
+pub fn attr<MSG>(
+    name: impl Into<AttributeName>,
+    value: impl Into<AttributeValue<MSG>>,
+) -> Attribute<MSG> {
     attr_ns(None, name, value)
 }
 
@@ -211,7 +215,7 @@ pub fn attr<MSG>(name: AttributeName, value: impl Into<AttributeValue<MSG>>) ->
 #[inline]
 pub fn attr_ns<MSG>(
     namespace: Option<Namespace>,
-    name: AttributeName,
+    name: impl Into<AttributeName>,
     value: impl Into<AttributeValue<MSG>>,
 ) -> Attribute<MSG> {
     Attribute::new(namespace, name, value.into())
@@ -1,6 +1,6 @@
 //! provides diffing algorithm which returns patches
 use super::{diff_lis, Attribute, Element, Node, Patch, TreePath};
-use super::{Tag, KEY, REPLACE, SKIP, SKIP_CRITERIA};
+use super::{KEY, REPLACE, SKIP, SKIP_CRITERIA};
 use crate::dom::skip_diff::SkipAttrs;
 use crate::dom::SkipPath;
 use crate::vdom::AttributeValue;
@@ -44,7 +44,7 @@ static USE_SKIP_DIFF: bool = false;
 /// assert_eq!(
 ///     diff,
 ///     vec![Patch::remove_node(
-///         Some(&"div"),
+///         Some("div"),
 ///         TreePath::new(vec![ 0]),
 ///     )
 ///     ]
@@ -196,7 +196,7 @@ pub fn diff_recursive<'a, MSG>(
                 }
                 (Leaf::StatefulComponent(old_comp), Leaf::StatefulComponent(new_comp)) => {
                     let attr_patches = create_attribute_patches(
-                        &"component",
+                        "component",
                         &old_comp.attrs,
                         &new_comp.attrs,
                         path,
@@ -259,7 +259,7 @@ pub fn diff_recursive<'a, MSG>(
 }
 
 fn diff_nodes<'a, MSG>(
-    old_tag: Option<&'a Tag>,
+    old_tag: Option<&'a str>,
     old_children: &'a [Node<MSG>],
     new_children: &'a [Node<MSG>],
     path: &SkipPath,
@@ -286,7 +286,7 @@ fn diff_nodes<'a, MSG>(
 ///  If there are more children in the new_element than the old_element
 ///  it will be all appended in the old_element.
 fn diff_non_keyed_nodes<'a, MSG>(
-    old_element_tag: Option<&'a Tag>,
+    old_element_tag: Option<&'a str>,
     old_children: &'a [Node<MSG>],
     new_children: &'a [Node<MSG>],
     path: &SkipPath,
@@ -344,7 +344,7 @@ fn diff_non_keyed_nodes<'a, MSG>(
 ///     - merging attributes of the same name
 #[allow(clippy::type_complexity)]
 fn create_attribute_patches<'a, MSG>(
-    old_tag: &'a Tag,
+    old_tag: &'a str,
     old_attributes: &'a [Attribute<MSG>],
     new_attributes: &'a [Attribute<MSG>],
     path: &SkipPath,
@@ -1,13 +1,13 @@
 //! diff with longest increasing subsequence
 
 use super::diff::diff_recursive;
-use super::{AttributeValue, Tag, KEY};
+use super::{AttributeValue, KEY};
 use super::{Node, Patch};
 use crate::dom::SkipPath;
 use indexmap::IndexMap;
 
 pub fn diff_keyed_nodes<'a, MSG>(
-    old_tag: Option<&'a Tag>,
+    old_tag: Option<&'a str>,
     old_children: &'a [Node<MSG>],
     new_children: &'a [Node<MSG>],
     path: &SkipPath,
@@ -95,7 +95,7 @@ pub fn diff_keyed_nodes<'a, MSG>(
 }
 
 fn diff_keyed_ends<'a, MSG>(
-    old_tag: Option<&'a Tag>,
+    old_tag: Option<&'a str>,
     old_children: &'a [Node<MSG>],
     new_children: &'a [Node<MSG>],
     path: &SkipPath,
@@ -39,7 +39,7 @@ impl<MSG> Element<MSG> {
     /// create a new instance of an element
     pub fn new(
         namespace: Option<Namespace>,
-        tag: Tag,
+        tag: impl Into<Tag>,
         attrs: impl IntoIterator<Item = Attribute<MSG>>,
         children: impl IntoIterator<Item = Node<MSG>>,
         self_closing: bool,
@@ -54,7 +54,7 @@ impl<MSG> Element<MSG> {
             .collect();
         Self {
             namespace,
-            tag,
+            tag: tag.into(),
             attrs: attrs.into_iter().collect(),
             children,
             self_closing,
@@ -136,12 +136,12 @@ impl<MSG> Element<MSG> {
     }
 
     /// change the tag of this element
-    pub fn set_tag(&mut self, tag: Tag) {
-        self.tag = tag;
+    pub fn set_tag(&mut self, tag: impl Into<Tag>) {
+        self.tag = tag.into();
     }
 
     /// remove the attributes with this key
-    pub fn remove_attribute(&mut self, name: &AttributeName) {
+    pub fn remove_attribute(&mut self, name: &str) {
         self.attrs.retain(|att| att.name != *name)
     }
 
@@ -167,7 +167,7 @@ impl<MSG> Element<MSG> {
     }
 
     /// return all the attribute values which the name &AttributeName
-    pub fn attribute_value(&self, name: &AttributeName) -> Option<Vec<&AttributeValue<MSG>>> {
+    pub fn attribute_value(&self, name: &str) -> Option<Vec<&AttributeValue<MSG>>> {
         let result: Vec<&AttributeValue<MSG>> = self
             .attrs
             .iter()
@@ -183,7 +183,7 @@ impl<MSG> Element<MSG> {
     }
 
     /// get the first value of the attribute which has the name `att_name` of this element
-    pub fn first_value(&self, att_name: &AttributeName) -> Option<&Value> {
+    pub fn first_value(&self, att_name: &str) -> Option<&Value> {
         self.attribute_value(att_name)
             .and_then(|att_values| att_values.first().and_then(|v| v.get_simple()))
     }
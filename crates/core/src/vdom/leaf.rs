@@ -2,13 +2,15 @@
 use crate::dom::StatefulModel;
 use crate::dom::StatelessModel;
 use crate::vdom::Attribute;
-use crate::vdom::AttributeName;
 use crate::vdom::AttributeValue;
 use crate::vdom::Node;
 use crate::vdom::TemplatedView;
 use derive_where::derive_where;
 use std::borrow::Cow;
 
+/// the content of the comment inserted in between adjacent texts
+pub(crate) const SEPARATOR: &str = "separator";
+
 /// A leaf node value of html dom tree
 #[derive_where(Clone, Debug)]
 pub enum Leaf<MSG> {
@@ -60,6 +62,15 @@ impl<MSG> Leaf<MSG> {
         matches!(self, Self::Text(_))
     }
 
+    /// returns true if this is the separator comment which [`html_element`] inserts in between
+    /// adjacent texts. The separator is a static string, so a comment parsed from html such as
+    /// `<!--separator-->` is never mistaken as a separator
+    ///
+    /// [`html_element`]: crate::html::html_element
+    pub fn is_separator(&self) -> bool {
+        matches!(self, Self::Comment(Cow::Borrowed(SEPARATOR)))
+    }
+
     /// return the text content if it is a text node
     pub fn as_text(&self) -> Option<&str> {
         match self {
@@ -69,7 +80,7 @@ impl<MSG> Leaf<MSG> {
     }
 
     /// return the attribute value of this leaf
-    pub fn attribute_value(&self, name: &AttributeName) -> Option<Vec<&AttributeValue<MSG>>> {
+    pub fn attribute_value(&self, name: &str) -> Option<Vec<&AttributeValue<MSG>>> {
         match self {
             Self::StatelessComponent(comp) => comp.attribute_value(name),
             Self::TemplatedView(templated_view) => templated_view.view.attribute_value(name),
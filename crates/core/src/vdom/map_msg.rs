@@ -4,6 +4,7 @@ use crate::vdom::Element;
 use crate::vdom::Leaf;
 use crate::vdom::Node;
 use crate::vdom::TemplatedView;
+use std::rc::Rc;
 
 impl<MSG> Node<MSG> {
     /// map the msg of this node such that Node<MSG> becomes Node<MSG2>
@@ -127,9 +128,13 @@ impl<MSG> TemplatedView<MSG> {
         MSG2: 'static,
         MSG: 'static,
     {
+        let template = self.template;
+        let template_cb = cb.clone();
         TemplatedView {
-            view: Box::new(self.view.map_msg(cb.clone())),
+            view: Box::new(self.view.map_msg(cb)),
+            template: Rc::new(move || template().map_msg(template_cb.clone())),
             skip_diff: self.skip_diff,
+            call_site: self.call_site,
         }
     }
 }
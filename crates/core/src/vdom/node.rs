@@ -1,4 +1,4 @@
-use super::{AttributeName, Namespace, Tag};
+use super::{Namespace, Tag};
 use crate::dom::SkipDiff;
 use crate::vdom::Attribute;
 use crate::vdom::AttributeValue;
@@ -183,7 +183,7 @@ impl<MSG> Node<MSG> {
 
     /// returns the tag of this node if it is an element
     /// otherwise None if it is a text node
-    pub fn tag(&self) -> Option<&Tag> {
+    pub fn tag(&self) -> Option<&str> {
         if let Some(e) = self.element_ref() {
             Some(&e.tag)
         } else {
@@ -288,7 +288,7 @@ impl<MSG> Node<MSG> {
     }
 
     /// return the attribute values of this node which match the attribute name `name`
-    pub fn attribute_value(&self, name: &AttributeName) -> Option<Vec<&AttributeValue<MSG>>> {
+    pub fn attribute_value(&self, name: &str) -> Option<Vec<&AttributeValue<MSG>>> {
         match self {
             Self::Element(elm) => elm.attribute_value(name),
             Self::Leaf(leaf) => leaf.attribute_value(name),
@@ -296,7 +296,7 @@ impl<MSG> Node<MSG> {
     }
 
     /// get the first value of the attribute which has the name `att_name` of this node
-    pub fn first_value(&self, att_name: &AttributeName) -> Option<&Value> {
+    pub fn first_value(&self, att_name: &str) -> Option<&Value> {
         self.attribute_value(att_name)
             .and_then(|att_values| att_values.first().and_then(|v| v.get_simple()))
     }
@@ -343,7 +343,7 @@ impl<MSG> Node<MSG> {
 /// ```
 #[inline]
 pub fn element<MSG>(
-    tag: Tag,
+    tag: impl Into<Tag>,
     attrs: impl IntoIterator<Item = Attribute<MSG>>,
     children: impl IntoIterator<Item = Node<MSG>>,
 ) -> Node<MSG> {
@@ -365,7 +365,7 @@ pub fn element<MSG>(
 /// ```
 pub fn element_ns<MSG>(
     namespace: Option<Namespace>,
-    tag: Tag,
+    tag: impl Into<Tag>,
     attrs: impl IntoIterator<Item = Attribute<MSG>>,
     children: impl IntoIterator<Item = Node<MSG>>,
     self_closing: bool,
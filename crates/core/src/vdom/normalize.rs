@@ -0,0 +1,223 @@
+//! Normalize a node so the differences which do not matter when it is rendered,
+//! such as the order of the attributes, are removed.
+//! This is used to compare the nodes parsed from html with the nodes built in code.
+use crate::vdom::{Attribute, AttributeValue, Element, Leaf, Node, Style, Value};
+use indexmap::IndexMap;
+use std::borrow::Cow;
+
+/// the elements where the whitespace of the text content is significant
+const WHITESPACE_PRESERVED_TAGS: &[&str] = &["pre", "textarea", "script", "style"];
+
+/// the elements which are laid out in the line of the text around them,
+/// so the whitespace in between them and the text is rendered
+const INLINE_TAGS: &[&str] = &[
+    "a", "abbr", "b", "bdi", "bdo", "br", "button", "cite", "code", "data", "dfn", "em", "i",
+    "img", "input", "kbd", "label", "mark", "q", "s", "samp", "select", "small", "span", "strong",
+    "sub", "sup", "textarea", "time", "u", "var", "wbr",
+];
+
+impl<MSG> Node<MSG> {
+    /// normalize this node, so nodes which render into an equivalent html are equal:
+    /// - the node lists and fragments are flatten into their parent
+    /// - adjacent texts are joined along with the separator comments in between them and
+    ///   the runs of whitespace are collapsed into a single space.
+    ///   The whitespace at the start and end of the children and next to a block element is
+    ///   removed, while the whitespace next to text and inline elements such as `b` is kept,
+    ///   so `Hello <b>x</b>` is not equal to `Hello<b>x</b>`.
+    ///   The texts inside `pre`, `textarea`, `script` and `style` are kept as is
+    /// - the attributes of the same name are merged and sorted by name,
+    ///   the values are converted into string such that `width(100)` and `width("100")` are equal
+    /// - the classes are sorted and deduplicated
+    /// - the styles are sorted by name, where the last declaration of a property wins
+    ///
+    /// # Example
+    /// ```rust
+    /// use sauron::{html::{attributes::*, *}, Node};
+    ///
+    /// let parsed: Node<()> = div([id("x"), class("b a")], [text("\n  hello\n")]);
+    /// let built: Node<()> = div([class("a"), class("b"), id("x")], [text("hello")]);
+    /// assert_eq!(parsed.normalize(), built.normalize());
+    /// ```
+    pub fn normalize(self) -> Self {
+        let mut nodes = normalize_nodes(vec![self], false);
+        match nodes.len() {
+            0 => Node::Leaf(Leaf::NodeList(vec![])),
+            1 => nodes.remove(0),
+            _ => Node::Leaf(Leaf::NodeList(nodes)),
+        }
+    }
+
+    /// returns true if this node is equal to the other node when both are normalized,
+    /// see [`Node::normalize`]
+    pub fn semantic_eq(&self, other: &Self) -> bool {
+        self.clone().normalize() == other.clone().normalize()
+    }
+}
+
+/// normalize the nodes, the nodes lists are flatten and the texts are joined
+fn normalize_nodes<MSG>(nodes: Vec<Node<MSG>>, preserve_whitespace: bool) -> Vec<Node<MSG>> {
+    let mut normalized: Vec<Node<MSG>> = vec![];
+    for node in flatten(nodes) {
+        match node {
+            Node::Element(element) => {
+                normalized.push(Node::Element(normalize_element(element)));
+            }
+            Node::Leaf(Leaf::Text(content)) => {
+                if let Some(Node::Leaf(Leaf::Text(last))) = normalized.last_mut() {
+                    last.to_mut().push_str(&content);
+                } else {
+                    normalized.push(Node::Leaf(Leaf::Text(content)));
+                }
+            }
+            // inserted by `html_element` in between adjacent texts
+            Node::Leaf(leaf) if leaf.is_separator() => (),
+            Node::Leaf(Leaf::TemplatedView(view)) => {
+                normalized.extend(normalize_nodes(vec![*view.view], preserve_whitespace));
+            }
+            node => normalized.push(node),
+        }
+    }
+    if preserve_whitespace {
+        return normalized;
+    }
+    // whitespace is trimmed where it is not rendered, which is decided by the nodes around it
+    let is_inline: Vec<bool> = normalized.iter().map(is_inline).collect();
+    normalized
+        .into_iter()
+        .enumerate()
+        .filter_map(|(index, node)| match node {
+            Node::Leaf(Leaf::Text(content)) => {
+                let collapsed = collapse_whitespace(&content);
+                let after_inline = index > 0 && is_inline[index - 1];
+                let before_inline = is_inline.get(index + 1).copied().unwrap_or(false);
+                let trimmed = if after_inline {
+                    collapsed.as_str()
+                } else {
+                    collapsed.trim_start()
+                };
+                let trimmed = if before_inline {
+                    trimmed
+                } else {
+                    trimmed.trim_end()
+                };
+                (!trimmed.is_empty())
+                    .then(|| Node::Leaf(Leaf::Text(Cow::from(trimmed.to_string()))))
+            }
+            node => Some(node),
+        })
+        .collect()
+}
+
+/// replace the runs of whitespace with a single space
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::with_capacity(text.len());
+    for ch in text.chars() {
+        if !ch.is_whitespace() {
+            collapsed.push(ch);
+        } else if !collapsed.ends_with(' ') {
+            collapsed.push(' ');
+        }
+    }
+    collapsed
+}
+
+/// the texts and inline elements, the other leaves such as symbols are rendered inline too
+fn is_inline<MSG>(node: &Node<MSG>) -> bool {
+    match node {
+        Node::Element(element) => INLINE_TAGS.contains(&element.tag.as_ref()),
+        Node::Leaf(_) => true,
+    }
+}
+
+/// the node lists and fragments are unrolled into their parent
+fn flatten<MSG>(nodes: Vec<Node<MSG>>) -> Vec<Node<MSG>> {
+    let mut flattened = vec![];
+    for node in nodes {
+        match node {
+            Node::Leaf(Leaf::NodeList(nodes)) | Node::Leaf(Leaf::Fragment(nodes)) => {
+                flattened.extend(flatten(nodes))
+            }
+            node => flattened.push(node),
+        }
+    }
+    flattened
+}
+
+fn normalize_element<MSG>(element: Element<MSG>) -> Element<MSG> {
+    let preserve_whitespace = WHITESPACE_PRESERVED_TAGS.contains(&element.tag.as_ref());
+    Element {
+        attrs: normalize_attributes(element.attrs),
+        children: normalize_nodes(element.children, preserve_whitespace),
+        ..element
+    }
+}
+
+/// merge the attributes of the same name and sort them by name
+fn normalize_attributes<MSG>(attributes: Vec<Attribute<MSG>>) -> Vec<Attribute<MSG>> {
+    let mut merged: IndexMap<_, Attribute<MSG>> = IndexMap::new();
+    for attr in attributes {
+        match merged.get_mut(&(attr.name.clone(), attr.namespace)) {
+            Some(existing) => existing.value.extend(attr.value),
+            None => {
+                merged.insert((attr.name.clone(), attr.namespace), attr);
+            }
+        }
+    }
+    merged.sort_keys();
+    merged
+        .into_values()
+        .map(|attr| Attribute {
+            value: normalize_values(&attr.name, attr.value),
+            ..attr
+        })
+        .collect()
+}
+
+/// the plain values are joined into a single string value and the styles into a single style
+/// value, the event listeners are kept as is
+fn normalize_values<MSG>(name: &str, values: Vec<AttributeValue<MSG>>) -> Vec<AttributeValue<MSG>> {
+    let mut plain_values = vec![];
+    let mut styles: IndexMap<Cow<'static, str>, Value> = IndexMap::new();
+    let mut rest = vec![];
+    for value in values {
+        match value {
+            AttributeValue::Simple(value) => plain_values.push(value.to_string()),
+            AttributeValue::Style(declarations) => {
+                for style in declarations {
+                    // a property declared again replaces the previous one
+                    styles.insert(style.name, Value::from(style.value.to_string()));
+                }
+            }
+            AttributeValue::Empty => (),
+            value => rest.push(value),
+        }
+    }
+    let mut normalized = vec![];
+    if !plain_values.is_empty() {
+        let joined = if name == "class" {
+            let mut classes: Vec<&str> = plain_values
+                .iter()
+                .flat_map(|value| value.split_whitespace())
+                .collect();
+            classes.sort_unstable();
+            classes.dedup();
+            classes.join(" ")
+        } else {
+            plain_values.join(" ")
+        };
+        normalized.push(AttributeValue::Simple(Value::from(joined)));
+    }
+    if !styles.is_empty() {
+        styles.sort_keys();
+        normalized.push(AttributeValue::from_styles(
+            styles
+                .into_iter()
+                .map(|(name, value)| Style { name, value }),
+        ));
+    }
+    normalized.extend(rest);
+    if normalized.is_empty() {
+        normalized.push(AttributeValue::Empty);
+    }
+    normalized
+}
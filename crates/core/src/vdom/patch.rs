@@ -1,6 +1,5 @@
 //! patch module
 
-use super::Tag;
 use super::{Attribute, Node};
 use std::borrow::Cow;
 
@@ -63,7 +62,7 @@ mod tree_path;
 #[derive_where(Clone, Debug, PartialEq, Eq)]
 pub struct Patch<'a, MSG> {
     /// the tag of the node at patch_path
-    pub tag: Option<&'a Tag>,
+    pub tag: Option<&'a str>,
     /// the path to traverse to get to the target element
     pub patch_path: TreePath,
     /// the type of patch we are going to apply
@@ -143,13 +142,13 @@ impl<'a, MSG> Patch<'a, MSG> {
     }
 
     /// return the tag of this patch
-    pub fn tag(&self) -> Option<&Tag> {
+    pub fn tag(&self) -> Option<&str> {
         self.tag
     }
 
     /// create an InsertBeforeNode patch
     pub fn insert_before_node(
-        tag: Option<&'a Tag>,
+        tag: Option<&'a str>,
         patch_path: TreePath,
         nodes: impl IntoIterator<Item = &'a Node<MSG>>,
     ) -> Patch<'a, MSG> {
@@ -164,7 +163,7 @@ impl<'a, MSG> Patch<'a, MSG> {
 
     /// create an InsertAfterNode patch
     pub fn insert_after_node(
-        tag: Option<&'a Tag>,
+        tag: Option<&'a str>,
         patch_path: TreePath,
         nodes: Vec<&'a Node<MSG>>,
     ) -> Patch<'a, MSG> {
@@ -177,7 +176,7 @@ impl<'a, MSG> Patch<'a, MSG> {
 
     /// create a patch where we add children to the target node
     pub fn append_children(
-        tag: Option<&'a Tag>,
+        tag: Option<&'a str>,
         patch_path: TreePath,
         children: Vec<&'a Node<MSG>>,
     ) -> Patch<'a, MSG> {
@@ -190,7 +189,7 @@ impl<'a, MSG> Patch<'a, MSG> {
 
     /// create a patch where the target element that can be traverse
     /// using the patch path will be remove
-    pub fn remove_node(tag: Option<&'a Tag>, patch_path: TreePath) -> Patch<'a, MSG> {
+    pub fn remove_node(tag: Option<&'a str>, patch_path: TreePath) -> Patch<'a, MSG> {
         Patch {
             tag,
             patch_path,
@@ -199,7 +198,7 @@ impl<'a, MSG> Patch<'a, MSG> {
     }
 
     /// create a patch where the target element has to clear its children nodes
-    pub fn clear_children(tag: Option<&'a Tag>, patch_path: TreePath) -> Patch<'a, MSG> {
+    pub fn clear_children(tag: Option<&'a str>, patch_path: TreePath) -> Patch<'a, MSG> {
         Patch {
             tag,
             patch_path,
@@ -210,7 +209,7 @@ impl<'a, MSG> Patch<'a, MSG> {
     /// remove the nodes pointed at the `nodes_path` and insert them before the target element
     /// pointed at patch_path
     pub fn move_before_node(
-        tag: Option<&'a Tag>,
+        tag: Option<&'a str>,
         patch_path: TreePath,
         nodes_path: impl IntoIterator<Item = TreePath>,
     ) -> Patch<'a, MSG> {
@@ -226,7 +225,7 @@ impl<'a, MSG> Patch<'a, MSG> {
     /// remove the nodes pointed at the `nodes_path` and insert them after the target element
     /// pointed at patch_path
     pub fn move_after_node(
-        tag: Option<&'a Tag>,
+        tag: Option<&'a str>,
         patch_path: TreePath,
         nodes_path: impl IntoIterator<Item = TreePath>,
     ) -> Patch<'a, MSG> {
@@ -242,7 +241,7 @@ impl<'a, MSG> Patch<'a, MSG> {
     /// create a patch where a node is replaced by the `replacement` node.
     /// The target node to be replace is traverse using the `patch_path`
     pub fn replace_node(
-        tag: Option<&'a Tag>,
+        tag: Option<&'a str>,
         patch_path: TreePath,
         replacement: impl IntoIterator<Item = &'a Node<MSG>>,
     ) -> Patch<'a, MSG> {
@@ -257,7 +256,7 @@ impl<'a, MSG> Patch<'a, MSG> {
 
     /// create a patch where a new attribute is added to the target element
     pub fn add_attributes(
-        tag: &'a Tag,
+        tag: &'a str,
         patch_path: TreePath,
         attrs: impl IntoIterator<Item = &'a Attribute<MSG>>,
     ) -> Patch<'a, MSG> {
@@ -273,7 +272,7 @@ impl<'a, MSG> Patch<'a, MSG> {
     /// create patch where it remove attributes of the target element that can be traversed by the
     /// patch_path.
     pub fn remove_attributes(
-        tag: &'a Tag,
+        tag: &'a str,
         patch_path: TreePath,
         attrs: Vec<&'a Attribute<MSG>>,
     ) -> Patch<'a, MSG> {
@@ -234,8 +234,8 @@ mod tests {
 
     // index is the index of this code with respect to it's sibling
     fn assert_traverse_match(node: &Node<()>, node_idx: &mut usize, path: Vec<usize>) {
-        let id = node.attribute_value(&"id").unwrap()[0];
-        let class = node.attribute_value(&"class").unwrap()[0];
+        let id = node.attribute_value("id").unwrap()[0];
+        let class = node.attribute_value("class").unwrap()[0];
         assert_eq!(id.as_str(), Some(node_idx.to_string()).as_deref());
         assert_eq!(class.as_str(), Some(format_vec(&path)).as_deref());
         for (i, child) in node.children().iter().enumerate() {
@@ -247,8 +247,8 @@ mod tests {
     }
 
     fn traverse_tree_path(node: &Node<()>, path: &TreePath, node_idx: &mut usize) {
-        let id = node.attribute_value(&"id").unwrap()[0];
-        let class = node.attribute_value(&"class").unwrap()[0];
+        let id = node.attribute_value("id").unwrap()[0];
+        let class = node.attribute_value("class").unwrap()[0];
         assert_eq!(id.as_str(), Some(node_idx.to_string()).as_deref());
         assert_eq!(class.as_str(), Some(format_vec(&path.path)).as_deref());
         for (i, child) in node.children().iter().enumerate() {
@@ -7,6 +7,7 @@ use crate::{
     vdom::GroupedAttributeValues,
     vdom::{Attribute, Element, Leaf, Node},
 };
+use std::borrow::Cow;
 use std::fmt;
 
 const DEFAULT_INDENT_SIZE: usize = 2;
@@ -200,14 +201,25 @@ impl<MSG> Attribute<MSG> {
             .unwrap_or(false);
 
         // skip this attribute if the boolean attributes evaluates to false
-        let should_skip_attribute = boolean_attributes.contains(self.name()) && !bool_value;
+        let should_skip_attribute =
+            boolean_attributes.contains(&self.name().as_ref()) && !bool_value;
 
         if !should_skip_attribute {
             if let Some(merged_plain_values) = Value::merge_to_string(plain_values) {
-                write!(buffer, "{}=\"{}\"", self.name(), merged_plain_values)?;
+                write!(
+                    buffer,
+                    "{}=\"{}\"",
+                    self.name(),
+                    escape_attribute_value(&merged_plain_values)
+                )?;
             }
             if let Some(merged_styles) = Style::merge_to_string(styles) {
-                write!(buffer, "{}=\"{}\"", self.name(), merged_styles)?;
+                write!(
+                    buffer,
+                    "{}=\"{}\"",
+                    self.name(),
+                    escape_attribute_value(&merged_styles)
+                )?;
             }
         }
         Ok(())
@@ -221,6 +233,15 @@ impl<MSG> Attribute<MSG> {
     }
 }
 
+/// escape the characters which would end the quoted attribute value or start an entity
+fn escape_attribute_value(value: &str) -> Cow<'_, str> {
+    if value.contains(['&', '"']) {
+        Cow::from(value.replace('&', "&amp;").replace('"', "&quot;"))
+    } else {
+        Cow::from(value)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -269,4 +290,11 @@ mod test {
         view.render(&mut buffer).expect("must render");
         assert_eq!(expected, buffer);
     }
+
+    #[test]
+    fn test_render_escaped_attribute_value() {
+        let view: Node<()> = a(vec![href("/search?q=\"a\"&page=1")], vec![]);
+        let expected = r#"<a href="/search?q=&quot;a&quot;&amp;page=1"></a>"#;
+        assert_eq!(expected, view.render_to_string());
+    }
 }
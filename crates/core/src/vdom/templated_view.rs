@@ -7,15 +7,23 @@ use std::rc::Rc;
 pub struct TemplatedView<MSG> {
     /// The view node
     pub view: Box<Node<MSG>>,
+    /// The static skeleton of the view node, which only has the literal attributes and
+    /// the dynamic parts replaced with a placeholder
+    pub template: Rc<dyn Fn() -> Node<MSG>>,
     /// The skip_diff generated from the view node
     pub skip_diff: Rc<dyn Fn() -> SkipDiff>,
+    /// the location of the `view!` macro call along with the hash of its skeleton,
+    /// the template is registered once per call site
+    pub call_site: &'static str,
 }
 
 impl<MSG> Clone for TemplatedView<MSG> {
     fn clone(&self) -> Self {
         Self {
             view: self.view.clone(),
+            template: Rc::clone(&self.template),
             skip_diff: Rc::clone(&self.skip_diff),
+            call_site: self.call_site,
         }
     }
 }
@@ -24,7 +32,9 @@ impl<MSG> fmt::Debug for TemplatedView<MSG> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_struct("TemplatedView")
             .field("view", &self.view)
+            .field("template", &(self.template)())
             .field("skip_diff", &(self.skip_diff)())
+            .field("call_site", &self.call_site)
             .finish()
     }
 }
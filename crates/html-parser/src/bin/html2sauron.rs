@@ -0,0 +1,54 @@
+//! Convert html into the rust code of a sauron view.
+//!
+//! Usage: `html2sauron [--node] [FILE]`
+//!
+//! The html is read from `FILE`, or from the standard input if no file is given.
+//! The code is written in the builder syntax, or in the `node!` macro syntax with `--node`.
+use sauron_html_parser::codegen::{html_to_rust, Syntax};
+use std::io::Read;
+use std::process::ExitCode;
+
+const USAGE: &str = "Usage: html2sauron [--node] [FILE]";
+
+fn main() -> ExitCode {
+    let mut syntax = Syntax::Builder;
+    let mut file = None;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--node" => syntax = Syntax::NodeMacro,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return ExitCode::SUCCESS;
+            }
+            _ if file.is_none() && !arg.starts_with('-') => file = Some(arg),
+            _ => {
+                eprintln!("{USAGE}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+    let html = match &file {
+        Some(file) => std::fs::read_to_string(file),
+        None => {
+            let mut html = String::new();
+            std::io::stdin().read_to_string(&mut html).map(|_| html)
+        }
+    };
+    let html = match html {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("unable to read the html: {e}");
+            return ExitCode::FAILURE;
+        }
+    };
+    match html_to_rust(&html, syntax) {
+        Ok(code) => {
+            println!("{code}");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("unable to parse the html: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
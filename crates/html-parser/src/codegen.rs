@@ -0,0 +1,406 @@
+//! Generate the rust code of a view from html, such as turning `<div class="x">hello</div>`
+//! into `div([class("x")], [text("hello")])` or the equivalent `node!` macro.
+//! The generated code expects `use sauron::*;` to be in scope.
+use crate::{parse_html, ParseError, MATHML_NAMESPACE};
+use sauron_core::{
+    html::{
+        attributes::{HTML_ATTRS, HTML_ATTRS_SPECIAL},
+        tags::{
+            commons::HTML_TAGS, self_closing::HTML_SC_TAGS, HTML_TAGS_NON_COMMON,
+            HTML_TAGS_WITH_MACRO_NON_COMMON,
+        },
+    },
+    svg::{
+        attributes::{SVG_ATTRS, SVG_ATTRS_SPECIAL, SVG_ATTRS_XLINK},
+        tags::{commons::SVG_TAGS, special::SVG_TAGS_SPECIAL, SVG_TAGS_NON_COMMON},
+        SVG_NAMESPACE,
+    },
+    vdom::{Attribute, AttributeValue, Element, Leaf, Node},
+};
+
+/// the generated lines are wrapped when they are longer than this
+const MAX_WIDTH: usize = 100;
+
+/// the html self closing tags which are in `sauron::prelude`
+const PRELUDE_SC_TAGS: [&str; 4] = ["br", "hr", "img", "input"];
+
+/// attributes which are omitted when rendered if their value is not true
+const BOOLEAN_ATTRIBUTES: [&str; 3] = ["open", "checked", "disabled"];
+
+/// the syntax of the generated code
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Syntax {
+    /// function calls, such as `div([class("x")], [text("hello")])`
+    #[default]
+    Builder,
+    /// the `node!` macro, such as `node! { <div class="x">"hello"</div> }`
+    NodeMacro,
+}
+
+/// parse the html and generate the rust code of the view in the `syntax`
+/// ```rust
+/// use sauron_html_parser::codegen::{html_to_rust, Syntax};
+///
+/// let code = html_to_rust(r#"<div class="x">hello</div>"#, Syntax::Builder).unwrap();
+/// assert_eq!(code, r#"div([class("x")], [text("hello")])"#);
+/// ```
+pub fn html_to_rust(html: &str, syntax: Syntax) -> Result<String, ParseError> {
+    let node: Option<Node<()>> = parse_html(html)?;
+    Ok(node
+        .map(|node| node_to_rust(&node, syntax))
+        .unwrap_or_default())
+}
+
+/// generate the rust code of this node in the `syntax`
+pub fn node_to_rust<MSG>(node: &Node<MSG>, syntax: Syntax) -> String {
+    match syntax {
+        Syntax::Builder => builder(node, 0).unwrap_or_else(|| "html::node_list([])".to_string()),
+        Syntax::NodeMacro => {
+            let roots = match node {
+                Node::Leaf(Leaf::NodeList(nodes)) | Node::Leaf(Leaf::Fragment(nodes)) => {
+                    nodes.iter().collect()
+                }
+                node => vec![node],
+            };
+            let mut code = String::from("node! {\n");
+            for root in roots {
+                if let Some(root) = node_macro(root, 1) {
+                    code.push_str(&indent(1));
+                    code.push_str(&root);
+                    code.push('\n');
+                }
+            }
+            code.push('}');
+            code
+        }
+    }
+}
+
+/// an argument of a function call
+enum Arg {
+    /// a single expression
+    Expr(String),
+    /// an array of expressions, already formatted as items of a multi-line array
+    List(Vec<String>),
+}
+
+impl Arg {
+    /// the argument in a single line, None if it doesn't fit in a single line
+    fn flat(&self) -> Option<String> {
+        match self {
+            Self::Expr(expr) => (!expr.contains('\n')).then(|| expr.clone()),
+            Self::List(items) => items
+                .iter()
+                .all(|item| !item.contains('\n'))
+                .then(|| format!("[{}]", items.join(", "))),
+        }
+    }
+
+    /// the argument as one of the lines of a multi-line function call
+    fn multi_line(&self, level: usize) -> String {
+        match self {
+            Self::Expr(expr) => expr.clone(),
+            Self::List(items) => match self.flat() {
+                Some(flat) if fits(level, &flat) => flat,
+                _ => {
+                    let mut code = String::from("[\n");
+                    for item in items {
+                        code.push_str(&format!("{}{item},\n", indent(level + 1)));
+                    }
+                    code.push_str(&indent(level));
+                    code.push(']');
+                    code
+                }
+            },
+        }
+    }
+}
+
+fn indent(level: usize) -> String {
+    "    ".repeat(level)
+}
+
+fn fits(level: usize, line: &str) -> bool {
+    level * 4 + line.len() <= MAX_WIDTH
+}
+
+/// format the function call in a single line if it fits, otherwise an argument per line
+fn call(function: &str, args: Vec<Arg>, level: usize) -> String {
+    let flat: Option<Vec<String>> = args.iter().map(Arg::flat).collect();
+    if let Some(flat) = flat {
+        let line = format!("{function}({})", flat.join(", "));
+        if fits(level, &line) {
+            return line;
+        }
+    }
+    let mut code = format!("{function}(\n");
+    for arg in args {
+        code.push_str(&format!(
+            "{}{},\n",
+            indent(level + 1),
+            arg.multi_line(level + 1)
+        ));
+    }
+    code.push_str(&indent(level));
+    code.push(')');
+    code
+}
+
+/// whitespace between tags and the separator inserted between text nodes are not part of the view,
+/// while a `<!--separator-->` comment in the html is kept
+fn is_skipped<MSG>(node: &Node<MSG>) -> bool {
+    match node {
+        Node::Leaf(Leaf::Text(text)) => text.trim().is_empty(),
+        Node::Leaf(leaf) => leaf.is_separator(),
+        _ => false,
+    }
+}
+
+fn builder<MSG>(node: &Node<MSG>, level: usize) -> Option<String> {
+    if is_skipped(node) {
+        return None;
+    }
+    match node {
+        Node::Element(elm) => Some(builder_element(elm, level)),
+        Node::Leaf(leaf) => match leaf {
+            Leaf::Text(text) => Some(format!("text({text:?})")),
+            Leaf::Symbol(symbol) => Some(format!("html::symbol({symbol:?})")),
+            Leaf::Comment(comment) => Some(format!("comment({comment:?})")),
+            Leaf::DocType(doctype) => Some(format!("html::doctype({doctype:?})")),
+            Leaf::NodeList(nodes) => Some(call(
+                "html::node_list",
+                vec![builder_children(nodes, level)],
+                level,
+            )),
+            Leaf::Fragment(nodes) => Some(call(
+                "html::fragment",
+                vec![builder_children(nodes, level)],
+                level,
+            )),
+            // these are not created from html
+            _ => None,
+        },
+    }
+}
+
+fn builder_children<MSG>(nodes: &[Node<MSG>], level: usize) -> Arg {
+    Arg::List(
+        nodes
+            .iter()
+            .filter_map(|node| builder(node, level + 2))
+            .collect(),
+    )
+}
+
+fn builder_element<MSG>(elm: &Element<MSG>, level: usize) -> String {
+    let tag: &str = elm.tag();
+    let namespace = elm.namespace().copied();
+    let (function, mut args) = element_function(tag, namespace);
+    args.push(Arg::List(
+        elm.attributes()
+            .iter()
+            .map(|attr| builder_attribute(attr, namespace))
+            .collect(),
+    ));
+    args.push(builder_children(elm.children(), level));
+    if function == "html::html_element" {
+        args.push(Arg::Expr(elm.self_closing.to_string()));
+    }
+    call(&function, args, level)
+}
+
+/// the function which creates the element and its leading arguments.
+/// Elements which don't have a function are created with `html::element`
+/// or `html::html_element` with their namespace.
+fn element_function(tag: &str, namespace: Option<&'static str>) -> (String, Vec<Arg>) {
+    let tag_arg = Arg::Expr(format!("{tag:?}"));
+    match namespace {
+        None => {
+            if HTML_TAGS.contains(&tag) || PRELUDE_SC_TAGS.contains(&tag) {
+                (tag.to_string(), vec![])
+            } else if HTML_SC_TAGS.contains(&tag)
+                || HTML_TAGS_NON_COMMON.contains(&tag)
+                || HTML_TAGS_WITH_MACRO_NON_COMMON.contains(&tag)
+            {
+                (format!("html::{tag}"), vec![])
+            } else {
+                ("html::element".to_string(), vec![tag_arg])
+            }
+        }
+        Some(SVG_NAMESPACE) => {
+            if SVG_TAGS.contains(&tag) {
+                (tag.to_string(), vec![])
+            } else if let Some((function, _)) = SVG_TAGS_SPECIAL.iter().find(|(_, t)| *t == tag) {
+                (function.to_string(), vec![])
+            } else if SVG_TAGS_NON_COMMON.contains(&tag) {
+                (format!("svg::tags::{tag}"), vec![])
+            } else {
+                (
+                    "html::html_element".to_string(),
+                    vec![Arg::Expr("Some(svg::SVG_NAMESPACE)".to_string()), tag_arg],
+                )
+            }
+        }
+        Some(namespace) => (
+            "html::html_element".to_string(),
+            vec![Arg::Expr(format!("Some({namespace:?})")), tag_arg],
+        ),
+    }
+}
+
+/// the function which creates the attribute, None if it has to be created with `attr`
+fn attribute_function(name: &str, namespace: Option<&'static str>) -> Option<String> {
+    let svg_function = || {
+        if let Some(local) = name.strip_prefix("xlink:") {
+            SVG_ATTRS_XLINK
+                .iter()
+                .find(|(_, att)| *att == local)
+                .map(|(function, _)| function.to_string())
+        } else if SVG_ATTRS.contains(&name) {
+            Some(name.to_string())
+        } else {
+            SVG_ATTRS_SPECIAL
+                .iter()
+                .find(|(_, att)| *att == name)
+                .map(|(function, _)| function.to_string())
+        }
+    };
+    let html_function = || {
+        if HTML_ATTRS.contains(&name) {
+            Some(name.to_string())
+        } else {
+            HTML_ATTRS_SPECIAL
+                .iter()
+                .find(|(_, att)| *att == name)
+                .map(|(function, _)| format!("html::attributes::{function}"))
+        }
+    };
+    match namespace {
+        Some(SVG_NAMESPACE) => svg_function().or_else(html_function),
+        Some(_) => None,
+        None => html_function(),
+    }
+}
+
+/// the plain values of the attribute joined together, None if the attribute has no value
+fn plain_value<MSG>(attr: &Attribute<MSG>) -> Option<String> {
+    let values: Vec<String> = attr
+        .value()
+        .iter()
+        .filter_map(|value| match value {
+            AttributeValue::Simple(value) => Some(value.to_string()),
+            _ => None,
+        })
+        .collect();
+    (!values.is_empty()).then(|| values.join(" "))
+}
+
+/// the style values of the attribute as pairs of name and value
+fn style_values<MSG>(attr: &Attribute<MSG>) -> Vec<(String, String)> {
+    attr.value()
+        .iter()
+        .filter_map(|value| match value {
+            AttributeValue::Style(styles) => Some(styles),
+            _ => None,
+        })
+        .flatten()
+        .map(|style| (style.name.to_string(), style.value.to_string()))
+        .collect()
+}
+
+fn builder_attribute<MSG>(attr: &Attribute<MSG>, namespace: Option<&'static str>) -> String {
+    let name: &str = attr.name();
+    let styles = style_values(attr);
+    if !styles.is_empty() {
+        let pairs: Vec<String> = styles
+            .iter()
+            .map(|(name, value)| format!("({name:?}, {value:?})"))
+            .collect();
+        return format!("html::attributes::styles([{}])", pairs.join(", "));
+    }
+    let value = match plain_value(attr) {
+        Some(value) => format!("{value:?}"),
+        None if BOOLEAN_ATTRIBUTES.contains(&name) => "true".to_string(),
+        None => "\"\"".to_string(),
+    };
+    match attribute_function(name, namespace) {
+        Some(function) => format!("{function}({value})"),
+        None => format!("attr({name:?}, {value})"),
+    }
+}
+
+/// elements which the `node!` macro can not put in the proper namespace
+/// are written in the builder syntax inside a block
+fn is_node_macro_namespace(tag: &str, namespace: Option<&'static str>) -> bool {
+    sauron_core::html::lookup::tag_namespace(tag) == namespace
+        && namespace != Some(MATHML_NAMESPACE)
+}
+
+fn node_macro<MSG>(node: &Node<MSG>, level: usize) -> Option<String> {
+    if is_skipped(node) {
+        return None;
+    }
+    match node {
+        Node::Element(elm) if is_node_macro_namespace(elm.tag(), elm.namespace().copied()) => {
+            Some(node_macro_element(elm, level))
+        }
+        Node::Leaf(Leaf::Text(text)) => Some(format!("{text:?}")),
+        Node::Leaf(Leaf::Comment(comment)) => Some(format!("<!-- {comment:?} -->")),
+        Node::Leaf(Leaf::DocType(doctype)) => Some(format!("<!DOCTYPE {doctype}>")),
+        node => builder(node, level).map(|code| format!("{{{code}}}")),
+    }
+}
+
+fn node_macro_attribute<MSG>(attr: &Attribute<MSG>) -> String {
+    let name: &str = attr.name();
+    let styles = style_values(attr);
+    let value = if !styles.is_empty() {
+        let styles: Vec<String> = styles
+            .iter()
+            .map(|(name, value)| format!("{name}:{value};"))
+            .collect();
+        format!("{:?}", styles.join(""))
+    } else {
+        match plain_value(attr) {
+            Some(value) => format!("{value:?}"),
+            None if BOOLEAN_ATTRIBUTES.contains(&name) => "true".to_string(),
+            None => "\"\"".to_string(),
+        }
+    };
+    format!("{name}={value}")
+}
+
+fn node_macro_element<MSG>(elm: &Element<MSG>, level: usize) -> String {
+    let tag = elm.tag();
+    let attributes: Vec<String> = elm.attributes().iter().map(node_macro_attribute).collect();
+    let open_tag = if attributes.is_empty() {
+        format!("<{tag}")
+    } else {
+        format!("<{tag} {}", attributes.join(" "))
+    };
+    let children: Vec<String> = elm
+        .children()
+        .iter()
+        .filter_map(|child| node_macro(child, level + 1))
+        .collect();
+    if children.is_empty() && elm.self_closing {
+        return format!("{open_tag}/>");
+    }
+    let is_inline = elm
+        .children()
+        .iter()
+        .all(|child| !matches!(child, Node::Element(_)))
+        && children.iter().all(|child| !child.contains('\n'));
+    if is_inline {
+        let line = format!("{open_tag}>{}</{tag}>", children.join(""));
+        if fits(level, &line) {
+            return line;
+        }
+    }
+    let mut code = format!("{open_tag}>\n");
+    for child in children {
+        code.push_str(&format!("{}{child}\n", indent(level + 1)));
+    }
+    code.push_str(&format!("{}</{tag}>", indent(level)));
+    code
+}
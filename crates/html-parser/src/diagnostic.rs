@@ -0,0 +1,130 @@
+use std::fmt;
+
+/// a location in the html source, both the line and column starts at 1
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Position {
+    /// the line number
+    pub line: usize,
+    /// the column in characters
+    pub column: usize,
+}
+
+/// converts the character offsets of the parsed nodes into positions
+pub struct Lines {
+    /// the html source, the nodes are located by their character offset
+    source: Vec<char>,
+    /// the character offset where each line starts
+    starts: Vec<usize>,
+}
+
+impl Lines {
+    /// the lines of this html source
+    pub fn new(source: &str) -> Self {
+        let mut starts = vec![0];
+        starts.extend(
+            source
+                .chars()
+                .enumerate()
+                .filter(|(_, c)| *c == '\n')
+                .map(|(offset, _)| offset + 1),
+        );
+        Self {
+            source: source.chars().collect(),
+            starts,
+        }
+    }
+
+    /// the position of the character at this offset
+    pub fn position(&self, offset: usize) -> Position {
+        let line = self.starts.partition_point(|start| *start <= offset);
+        Position {
+            line,
+            column: offset - self.starts[line - 1] + 1,
+        }
+    }
+
+    /// the position of the attribute key, which is searched in the start tag of its element
+    pub fn attribute_position(&self, node: &rphtml::parser::Node, key: &str) -> Position {
+        let key: Vec<char> = key.chars().collect();
+        let begin = node.begin_at.min(self.source.len());
+        let end = self.source[begin..]
+            .iter()
+            .position(|c| *c == '>')
+            .map_or(self.source.len(), |end| begin + end);
+        let tag_source = &self.source[begin..end];
+        let offset = tag_source
+            .windows(key.len())
+            .enumerate()
+            .skip(1)
+            .find(|(i, window)| *window == key.as_slice() && tag_source[i - 1].is_whitespace())
+            .map(|(i, _)| node.begin_at + i)
+            .unwrap_or(node.begin_at);
+        self.position(offset)
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+/// a problem in the html which was recovered from when parsing in lenient mode
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic {
+    /// the tag is not a valid html, it is dropped and its children are kept in its place
+    UnknownTag {
+        /// the tag name
+        tag: String,
+        /// where the tag starts
+        position: Position,
+    },
+    /// the attribute is not a valid html attribute, it is dropped
+    UnknownAttribute {
+        /// the tag of the element
+        tag: String,
+        /// the attribute name
+        attribute: String,
+        /// where the attribute starts
+        position: Position,
+    },
+    /// the tag has no end tag, it is closed at the end of its parent
+    UnclosedTag {
+        /// the tag name
+        tag: String,
+        /// where the tag starts
+        position: Position,
+    },
+}
+
+impl Diagnostic {
+    /// where in the html source this diagnostic is located
+    pub fn position(&self) -> Position {
+        match self {
+            Self::UnknownTag { position, .. }
+            | Self::UnknownAttribute { position, .. }
+            | Self::UnclosedTag { position, .. } => *position,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownTag { tag, position } => {
+                write!(f, "{position}: unknown tag `{tag}` is dropped")
+            }
+            Self::UnknownAttribute {
+                tag,
+                attribute,
+                position,
+            } => write!(
+                f,
+                "{position}: unknown attribute `{attribute}` in `{tag}` is dropped"
+            ),
+            Self::UnclosedTag { tag, position } => {
+                write!(f, "{position}: tag `{tag}` is not closed")
+            }
+        }
+    }
+}
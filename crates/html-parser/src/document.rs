@@ -0,0 +1,323 @@
+//! A complete html page parsed into its doctype, head and body,
+//! so it can be manipulated and rendered back, ie: as the template of a server side rendered page
+//!
+//! # Example
+//! ```rust
+//! use sauron_core::html::{attributes::id, div};
+//! use sauron_html_parser::document::parse_document;
+//!
+//! let mut doc = parse_document::<()>(
+//!     "<!doctype html><html lang=\"en\"><head><title>Old</title></head><body></body></html>",
+//! )
+//! .expect("must parse");
+//! doc.set_title("App");
+//! doc.add_stylesheet("/style.css");
+//! doc.set_body([div([id("app")], [])]);
+//! assert_eq!(
+//!     doc.render_to_string(),
+//!     "<!doctype html><html lang=\"en\"><head><title>App</title><link rel=\"stylesheet\" href=\"/style.css\"/></head><body><div id=\"app\"></div></body></html>"
+//! );
+//! ```
+use crate::{parse_html, ParseError};
+use sauron_core::{
+    html::{attributes::attr, body, doctype, head, html, link, meta, script, style, text, title},
+    vdom::{Attribute, Leaf, Node},
+};
+use std::fmt;
+
+/// the elements which belongs to the head when the html has no `<head>` element
+const HEAD_TAGS: &[&str] = &["base", "link", "meta", "script", "style", "title"];
+
+/// a html page, with the content of its head and body
+#[derive(Debug)]
+pub struct Document<MSG> {
+    /// the name of the doctype, ie: `html` in `<!DOCTYPE html>`
+    pub doctype: Option<String>,
+    /// the attributes of the `<html>` element such as `lang`
+    pub html_attributes: Vec<Attribute<MSG>>,
+    /// the children of the `<head>` element such as `<title>`, `<meta>`, `<link>`, `<style>`
+    /// and `<script>`
+    pub head: Vec<Node<MSG>>,
+    /// the attributes of the `<body>` element
+    pub body_attributes: Vec<Attribute<MSG>>,
+    /// the children of the `<body>` element
+    pub body: Vec<Node<MSG>>,
+}
+
+/// parse a complete html page into a document.
+/// The `<html>`, `<head>` and `<body>` elements are optional, when omitted the title, meta,
+/// link, style and script elements before the content of the page are placed in the head.
+/// The doctype is only set when the html has one.
+pub fn parse_document<MSG>(html: &str) -> Result<Document<MSG>, ParseError> {
+    let node = parse_html(html)?.unwrap_or_else(|| Node::Leaf(Leaf::NodeList(vec![])));
+    Ok(Document::from(node))
+}
+
+// not derived, since the nodes can be cloned whether or not `MSG` can be cloned
+impl<MSG> Clone for Document<MSG> {
+    fn clone(&self) -> Self {
+        Self {
+            doctype: self.doctype.clone(),
+            html_attributes: self.html_attributes.clone(),
+            head: self.head.clone(),
+            body_attributes: self.body_attributes.clone(),
+            body: self.body.clone(),
+        }
+    }
+}
+
+impl<MSG> Default for Document<MSG> {
+    fn default() -> Self {
+        Self {
+            doctype: Some("html".to_string()),
+            html_attributes: vec![],
+            head: vec![],
+            body_attributes: vec![],
+            body: vec![],
+        }
+    }
+}
+
+impl<MSG> Document<MSG> {
+    /// the text of the `<title>` element in the head, with its html entities decoded
+    pub fn title(&self) -> Option<String> {
+        self.head
+            .iter()
+            .find(|node| node.tag().is_some_and(|tag| tag == "title"))
+            .map(|node| {
+                let content: String = node
+                    .children()
+                    .iter()
+                    .filter_map(|child| child.as_text().or_else(|| child.as_symbol()))
+                    .collect();
+                html_escape::decode_html_entities(&content).into_owned()
+            })
+    }
+
+    /// replace the text of the `<title>` element, the title is added to the head if there is none
+    pub fn set_title(&mut self, value: impl ToString) {
+        let new_title = title([], [text(value)]);
+        match self
+            .head
+            .iter_mut()
+            .find(|node| node.tag().is_some_and(|tag| tag == "title"))
+        {
+            Some(existing) => *existing = new_title,
+            None => self.head.insert(0, new_title),
+        }
+    }
+
+    /// the content of the `<meta>` element with this name, ie: `description`
+    pub fn meta(&self, name: &str) -> Option<String> {
+        self.find_meta(name)
+            .and_then(|index| self.head[index].first_value("content"))
+            .map(|value| value.to_string())
+    }
+
+    /// set the content of the `<meta>` element with this name,
+    /// the meta is added to the head if there is none
+    pub fn set_meta(&mut self, name: &str, content: &str) {
+        let new_meta = meta(
+            [
+                attr("name", name.to_string()),
+                attr("content", content.to_string()),
+            ],
+            [],
+        );
+        match self.find_meta(name) {
+            Some(index) => self.head[index] = new_meta,
+            None => self.head.push(new_meta),
+        }
+    }
+
+    fn find_meta(&self, name: &str) -> Option<usize> {
+        self.head.iter().position(|node| {
+            node.tag().is_some_and(|tag| tag == "meta")
+                && node
+                    .first_value("name")
+                    .is_some_and(|value| value.to_string().eq_ignore_ascii_case(name))
+        })
+    }
+
+    /// add a `<link rel="stylesheet">` to the head
+    pub fn add_stylesheet(&mut self, href: &str) {
+        self.head.push(link(
+            [attr("rel", "stylesheet"), attr("href", href.to_string())],
+            [],
+        ));
+    }
+
+    /// add a `<style>` with this css to the head
+    pub fn add_style(&mut self, css: impl ToString) {
+        self.head.push(style([], [text(css)]));
+    }
+
+    /// add a `<script>` which loads this url to the head
+    pub fn add_script(&mut self, src: &str) {
+        self.head.push(script([attr("src", src.to_string())], []));
+    }
+
+    /// add a `<script>` with this code to the head
+    pub fn add_inline_script(&mut self, code: impl ToString) {
+        self.head.push(script([], [text(code)]));
+    }
+
+    /// replace the content of the body, ie: with the view of the app
+    pub fn set_body(&mut self, nodes: impl IntoIterator<Item = Node<MSG>>) {
+        self.body = nodes.into_iter().collect();
+    }
+
+    /// the node of the whole page, starting with the doctype
+    pub fn into_node(self) -> Node<MSG> {
+        let page = html(
+            self.html_attributes,
+            [head([], self.head), body(self.body_attributes, self.body)],
+        );
+        match self.doctype {
+            Some(name) => Node::Leaf(Leaf::NodeList(vec![doctype(name), page])),
+            None => page,
+        }
+    }
+
+    /// render the page to a writable buffer
+    pub fn render(&self, buffer: &mut dyn fmt::Write) -> fmt::Result {
+        self.render_with_indent(buffer, false)
+    }
+
+    /// render the page into a compressed html
+    pub fn render_to_string(&self) -> String {
+        let mut buffer = String::new();
+        self.render_with_indent(&mut buffer, true)
+            .expect("must render");
+        buffer
+    }
+
+    /// render the page the same as its node from [`Document::into_node`],
+    /// without cloning the head and body into the `<html>` element
+    fn render_with_indent(&self, buffer: &mut dyn fmt::Write, compressed: bool) -> fmt::Result {
+        if let Some(name) = &self.doctype {
+            write!(buffer, "<!doctype {name}>")?;
+        }
+        render_start_tag(buffer, "html", &self.html_attributes)?;
+        maybe_indent(buffer, 1, compressed)?;
+        render_start_tag::<MSG>(buffer, "head", &[])?;
+        render_children(buffer, &self.head, 2, compressed)?;
+        write!(buffer, "</head>")?;
+        maybe_indent(buffer, 1, compressed)?;
+        render_start_tag(buffer, "body", &self.body_attributes)?;
+        render_children(buffer, &self.body, 2, compressed)?;
+        write!(buffer, "</body>")?;
+        maybe_indent(buffer, 0, compressed)?;
+        write!(buffer, "</html>")
+    }
+}
+
+fn render_start_tag<MSG>(
+    buffer: &mut dyn fmt::Write,
+    tag: &str,
+    attributes: &[Attribute<MSG>],
+) -> fmt::Result {
+    write!(buffer, "<{tag}")?;
+    for attr in Attribute::merge_attributes_of_same_name(attributes.iter()) {
+        write!(buffer, " {}", attr.render_to_string())?;
+    }
+    write!(buffer, ">")
+}
+
+/// the children are rendered the same as in an element at the `indent` level,
+/// a lone text is not indented
+fn render_children<MSG>(
+    buffer: &mut dyn fmt::Write,
+    children: &[Node<MSG>],
+    indent: usize,
+    compressed: bool,
+) -> fmt::Result {
+    if let [child] = children {
+        if child.is_text() {
+            return child.render_with_indent(buffer, indent, compressed);
+        }
+    }
+    for child in children {
+        maybe_indent(buffer, indent, compressed)?;
+        child.render_with_indent(buffer, indent, compressed)?;
+    }
+    if !children.is_empty() {
+        maybe_indent(buffer, indent - 1, compressed)?;
+    }
+    Ok(())
+}
+
+fn maybe_indent(buffer: &mut dyn fmt::Write, indent: usize, compressed: bool) -> fmt::Result {
+    if !compressed {
+        write!(buffer, "\n{}", "  ".repeat(indent))?;
+    }
+    Ok(())
+}
+
+/// the nodes of a page are split into the head and body, this is also used for
+/// the pages built with `node!`
+impl<MSG> From<Node<MSG>> for Document<MSG> {
+    fn from(node: Node<MSG>) -> Self {
+        let mut doc = Document {
+            doctype: None,
+            ..Default::default()
+        };
+        let mut in_body = false;
+        doc.distribute(node, &mut in_body);
+        doc
+    }
+}
+
+impl<MSG> Document<MSG> {
+    /// place the node in the doctype, head or body of the document.
+    /// Only the head elements, comments and whitespace before the content of the page
+    /// are placed in the head, once a content of the body such as a text is encountered
+    /// the rest of the nodes are placed in the body.
+    fn distribute(&mut self, node: Node<MSG>, in_body: &mut bool) {
+        match node {
+            Node::Leaf(Leaf::NodeList(nodes)) | Node::Leaf(Leaf::Fragment(nodes)) => {
+                for node in nodes {
+                    self.distribute(node, in_body);
+                }
+            }
+            Node::Leaf(Leaf::DocType(name)) => self.doctype = Some(name.to_string()),
+            // the whitespace in between the elements of the page is insignificant
+            Node::Leaf(Leaf::Text(content)) if !*in_body && content.trim().is_empty() => (),
+            Node::Element(element) => match element.tag().as_ref() {
+                "html" => {
+                    self.html_attributes.extend(element.attributes().to_vec());
+                    for child in element.take_children() {
+                        self.distribute(child, in_body);
+                    }
+                }
+                "head" => {
+                    for child in element.take_children() {
+                        match child {
+                            Node::Leaf(Leaf::Text(content)) if content.trim().is_empty() => (),
+                            child => self.head.push(child),
+                        }
+                    }
+                }
+                "body" => {
+                    *in_body = true;
+                    self.body_attributes.extend(element.attributes().to_vec());
+                    self.body.extend(element.take_children());
+                }
+                tag if !*in_body && HEAD_TAGS.contains(&tag) => {
+                    self.head.push(Node::Element(element))
+                }
+                _ => {
+                    *in_body = true;
+                    self.body.push(Node::Element(element));
+                }
+            },
+            Node::Leaf(Leaf::Comment(content)) if !*in_body => {
+                self.head.push(Node::Leaf(Leaf::Comment(content)))
+            }
+            node => {
+                *in_body = true;
+                self.body.push(node);
+            }
+        }
+    }
+}
@@ -1,18 +1,35 @@
 #![deny(warnings)]
 use rphtml::config::ParseOptions;
 use rphtml::parser::Doc;
+use rphtml::parser::DocHolder;
 use rphtml::parser::NodeType;
 use rphtml::types::BoxDynError;
 use sauron_core::{
     html::{attributes::*, lookup, *},
+    svg::{attributes::XLINK_NAMESPACE, SVG_NAMESPACE},
+    vdom::AttributeName,
     vdom::AttributeValue,
+    vdom::Leaf,
     vdom::Node,
+    vdom::Style,
+    vdom::Tag,
     vdom::Value,
 };
 use std::fmt;
 use std::io;
 use std::ops::Deref;
 
+pub use diagnostic::{Diagnostic, Lines, Position};
+
+pub mod codegen;
+mod diagnostic;
+pub mod document;
+pub mod sanitize;
+
+/// re-export of the underlying html parser, so the parsed document of [`parse_doc_strict`]
+/// can be traversed
+pub use rphtml;
+
 /// all the possible error when parsing html string
 #[derive(Debug, thiserror::Error)]
 pub enum ParseError {
@@ -26,21 +43,105 @@ pub enum ParseError {
     #[error("{0}")]
     RpHtmlError(#[from] BoxDynError),
     /// the tag is not a valid html
-    #[error("Invalid tag: {0}")]
-    InvalidTag(String),
+    #[error("Invalid tag: {0} at {1}")]
+    InvalidTag(String, Position),
+    /// the attribute is not a valid html attribute
+    #[error("Invalid attribute: {0} at {1}")]
+    InvalidAttribute(String, Position),
+}
+
+/// MathML namespace const, used for the elements inside a `<math>` element
+pub const MATHML_NAMESPACE: &str = "http://www.w3.org/1998/Math/MathML";
+
+/// the MathML core elements
+const MATHML_TAGS: &[&str] = &[
+    "math",
+    "annotation",
+    "annotation-xml",
+    "maction",
+    "menclose",
+    "merror",
+    "mfrac",
+    "mi",
+    "mmultiscripts",
+    "mn",
+    "mo",
+    "mover",
+    "mpadded",
+    "mphantom",
+    "mprescripts",
+    "mroot",
+    "mrow",
+    "ms",
+    "mspace",
+    "msqrt",
+    "mstyle",
+    "msub",
+    "msubsup",
+    "msup",
+    "mtable",
+    "mtd",
+    "mtext",
+    "mtr",
+    "munder",
+    "munderover",
+    "none",
+    "semantics",
+];
+
+/// how tags and attributes which are not in the html lookup tables are treated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// keep custom elements such as `<my-widget>` along with their attributes,
+    /// `data-*`, `aria-*` attributes and the unknown attributes of svg elements,
+    /// other unknown attributes are dropped
+    #[default]
+    Lenient,
+    /// reject any tag or attribute which is not a standard html or svg,
+    /// mathml attributes are not checked
+    Strict,
 }
 
-/// parse the html string and build a node tree
+/// parse the html string and build a node tree.
+/// The html is not sanitized, use [`sanitize::sanitize_html`] for html from untrusted sources.
+///
+/// # Panics
+/// panics if the html can not be parsed, use [`try_raw_html`] to handle the error
 pub fn raw_html<MSG>(html: &str) -> Node<MSG> {
-    // decode html entitiesd back since it will be safely converted into text
-    let html = html_escape::decode_html_entities(html);
-    parse_html(&html)
-        .expect("must be ok")
-        .expect("must have a node")
+    try_raw_html(html).expect("must be ok")
+}
+
+/// parse the html string and build a node tree,
+/// returns an error instead of panicking if the html can not be parsed
+pub fn try_raw_html<MSG>(html: &str) -> Result<Node<MSG>, ParseError> {
+    Ok(parse_html(html)?.unwrap_or_else(|| node_list([])))
 }
 
 /// the document is not wrapped with html
 pub fn parse_html<MSG>(html: &str) -> Result<Option<Node<MSG>>, ParseError> {
+    parse_html_with_mode(html, Mode::Lenient)
+}
+
+/// parse the html, where tags and attributes not in the lookup tables are treated
+/// according to the `mode`
+pub fn parse_html_with_mode<MSG>(html: &str, mode: Mode) -> Result<Option<Node<MSG>>, ParseError> {
+    Ok(parse_html_with_diagnostics(html, mode)?.node)
+}
+
+/// the node parsed from the html, along with the diagnostics of what was recovered from
+#[derive(Debug)]
+pub struct Parsed<MSG> {
+    /// the best-effort node of the html
+    pub node: Option<Node<MSG>>,
+    /// the unknown tags and attributes which were dropped and the tags which were not closed,
+    /// in the order they are encountered
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// parse the html, collecting the diagnostics of the unknown tags and attributes which
+/// are dropped and the unclosed tags which are fixed.
+/// In [`Mode::Strict`], the first unknown tag or attribute is returned as an error instead.
+pub fn parse_html_with_diagnostics<MSG>(html: &str, mode: Mode) -> Result<Parsed<MSG>, ParseError> {
     let doc = Doc::parse(
         html,
         ParseOptions {
@@ -51,82 +152,488 @@ pub fn parse_html<MSG>(html: &str) -> Result<Option<Node<MSG>>, ParseError> {
             auto_fix_unescaped_lt: true,
         },
     )?;
-    process_node(doc.get_root_node().borrow().deref())
+    let mut parser = Parser::new(html, mode);
+    let mut nodes = parser.process_node(doc.get_root_node().borrow().deref(), None)?;
+    Ok(Parsed {
+        node: nodes.pop(),
+        diagnostics: parser.diagnostics,
+    })
 }
 
-//TODO: This is not dealing with html symbols such as
-//   `&#9650;`
-//   `&#9660;`
-fn process_node<MSG>(node: &rphtml::parser::Node) -> Result<Option<Node<MSG>>, ParseError> {
-    let content = if let Some(content) = &node.content {
-        let content = String::from_iter(content.iter());
-        Some(content)
-    } else {
-        None
-    };
+/// parse the html string into a document without fixing malformed markup.
+/// Unclosed tags, unexpected end tags and unescaped `<` are reported as errors.
+/// This is used where the html has to be validated, such as in compile time templates.
+pub fn parse_doc_strict(html: &str) -> Result<DocHolder, ParseError> {
+    let doc = Doc::parse(
+        html,
+        ParseOptions {
+            case_sensitive_tagname: false,
+            allow_self_closing: true,
+            auto_fix_unclosed_tag: false,
+            auto_fix_unexpected_endtag: false,
+            auto_fix_unescaped_lt: false,
+        },
+    )?;
+    Ok(doc)
+}
 
-    let mut child_nodes = if let Some(childs) = &node.childs {
-        childs
-            .iter()
-            .flat_map(|child| process_node(child.borrow().deref()).ok().flatten())
-            .collect()
-    } else {
-        vec![]
-    };
+/// converts the parsed document into nodes, collecting the diagnostics of what was recovered from
+struct Parser {
+    mode: Mode,
+    diagnostics: Vec<Diagnostic>,
+    lines: Lines,
+}
+
+impl Parser {
+    fn new(html: &str, mode: Mode) -> Self {
+        Self {
+            mode,
+            diagnostics: vec![],
+            lines: Lines::new(html),
+        }
+    }
 
-    match node.node_type {
-        NodeType::Tag => {
-            let tag = &node.meta.as_ref().expect("must have a tag");
-            let tag_name = String::from_iter(tag.borrow().name.iter());
-            if let Some(html_tag) = lookup::match_tag(&tag_name) {
-                let is_self_closing = HTML_SC_TAGS.contains(&html_tag);
-                let attributes: Vec<Attribute<MSG>> = tag
-                    .borrow()
-                    .attrs
-                    .iter()
-                    .filter_map(|attr| {
-                        attr.key.as_ref().and_then(|key| {
-                            let key = String::from_iter(key.content.iter());
-                            if let Some(attr_key) = lookup::match_attribute(&key) {
-                                let value = if let Some(value) = &attr.value {
-                                    let value = String::from_iter(value.content.iter());
-                                    AttributeValue::Simple(Value::from(value))
-                                } else {
-                                    AttributeValue::Empty
-                                };
-                                Some(Attribute::new(None, attr_key, value))
-                            } else {
+    /// a text may be split into several text and symbol nodes.
+    /// The `namespace` is the namespace of the parent element, which switches to svg or mathml
+    /// inside an `<svg>` or `<math>` element
+    fn process_node<MSG>(
+        &mut self,
+        node: &rphtml::parser::Node,
+        namespace: Option<&'static str>,
+    ) -> Result<Vec<Node<MSG>>, ParseError> {
+        let mode = self.mode;
+        let position = self.lines.position(node.begin_at);
+        match node.node_type {
+            NodeType::Tag => {
+                let tag = &node.meta.as_ref().expect("must have a tag");
+                let tag_name = String::from_iter(tag.borrow().name.iter());
+                let namespace = element_namespace(&tag_name, namespace);
+                let is_custom_element =
+                    namespace.is_none() && mode == Mode::Lenient && is_custom_element(&tag_name);
+                let html_tag = match_tag(&tag_name, namespace)
+                    .map(Tag::Borrowed)
+                    .or_else(|| is_custom_element.then(|| Tag::Owned(tag_name.clone())));
+                let html_tag = match html_tag {
+                    Some(html_tag) => html_tag,
+                    None if mode == Mode::Lenient => {
+                        log::warn!("invalid tag: {}", tag_name);
+                        self.diagnostics.push(Diagnostic::UnknownTag {
+                            tag: tag_name,
+                            position,
+                        });
+                        // the children are kept in place of the unknown element
+                        return self.process_children(node, namespace);
+                    }
+                    None => {
+                        log::error!("invalid tag: {}", tag_name);
+                        return Err(ParseError::InvalidTag(tag_name, position));
+                    }
+                };
+                if tag.borrow().auto_fix {
+                    self.diagnostics.push(Diagnostic::UnclosedTag {
+                        tag: tag_name.clone(),
+                        position,
+                    });
+                }
+                let is_self_closing =
+                    namespace.is_none() && HTML_SC_TAGS.contains(&html_tag.as_ref());
+                let mut attributes: Vec<Attribute<MSG>> = vec![];
+                for attr in tag.borrow().attrs.iter() {
+                    let Some(key) = attr.key.as_ref() else {
+                        continue;
+                    };
+                    let key = String::from_iter(key.content.iter());
+                    let (attr_namespace, attr_key) = match match_attribute(&key, namespace) {
+                        Some(attr_key) => attr_key,
+                        None if mode == Mode::Strict => {
+                            let position = self.lines.attribute_position(node, &key);
+                            return Err(ParseError::InvalidAttribute(key, position));
+                        }
+                        None => {
+                            // the names are kept in their original case, which matters in svg
+                            let custom_key = (namespace.is_some()
+                                || is_custom_element
+                                || is_custom_attribute(&key))
+                            .then(|| AttributeName::Owned(key.clone()));
+                            let Some(custom_key) = custom_key else {
                                 log::warn!("Not a standard html attribute: {}", key);
-                                None
-                            }
-                        })
-                    })
-                    .collect();
-
-                Ok(Some(html_element(
-                    None,
+                                let position = self.lines.attribute_position(node, &key);
+                                self.diagnostics.push(Diagnostic::UnknownAttribute {
+                                    tag: tag_name.clone(),
+                                    attribute: key,
+                                    position,
+                                });
+                                continue;
+                            };
+                            (None, custom_key)
+                        }
+                    };
+                    let value = if let Some(value) = &attr.value {
+                        let value = String::from_iter(value.content.iter());
+                        let value = html_escape::decode_html_entities(&value).into_owned();
+                        let styles = if attr_key == "style" {
+                            parse_style(&value)
+                        } else {
+                            vec![]
+                        };
+                        if styles.is_empty() {
+                            AttributeValue::Simple(Value::from(value))
+                        } else {
+                            AttributeValue::from_styles(styles)
+                        }
+                    } else {
+                        AttributeValue::Empty
+                    };
+                    attributes.push(Attribute::new(attr_namespace, attr_key, value));
+                }
+                let children_namespace = children_namespace(&html_tag, namespace);
+                let child_nodes = if is_raw_text_element(&html_tag, namespace) {
+                    raw_text_children(node)
+                } else if is_rcdata_element(&html_tag, namespace) {
+                    rcdata_children(node)
+                } else {
+                    self.process_children(node, children_namespace)?
+                };
+
+                Ok(vec![html_element(
+                    namespace,
                     html_tag,
                     attributes,
                     child_nodes,
                     is_self_closing,
-                )))
+                )])
+            }
+            NodeType::Text => {
+                let content = node.content.as_ref().expect("must have a content");
+                Ok(decode_text(&String::from_iter(content.iter())))
+            }
+            NodeType::AbstractRoot => {
+                let mut child_nodes = self.process_children(node, namespace)?;
+                let child_nodes_len = child_nodes.len();
+                match child_nodes_len {
+                    0 => Ok(vec![node_list([])]),
+                    1 => Ok(vec![child_nodes.remove(0)]),
+                    _ => Ok(vec![node_list(child_nodes)]),
+                }
+            }
+            NodeType::Comment => {
+                let content = node.content.as_ref().expect("must have a content");
+                Ok(vec![comment(String::from_iter(content.iter()))])
+            }
+            NodeType::HTMLDOCTYPE => Ok(vec![doctype(doctype_name(node))]),
+            _ => Ok(vec![]),
+        }
+    }
+
+    fn process_children<MSG>(
+        &mut self,
+        node: &rphtml::parser::Node,
+        namespace: Option<&'static str>,
+    ) -> Result<Vec<Node<MSG>>, ParseError> {
+        let Some(childs) = &node.childs else {
+            return Ok(vec![]);
+        };
+        let mut child_nodes = vec![];
+        for child in childs.iter() {
+            child_nodes.extend(self.process_node(child.borrow().deref(), namespace)?);
+        }
+        Ok(child_nodes)
+    }
+}
+
+/// the namespace of the element, an `<svg>` or `<math>` element starts a new namespace
+/// which its descendants inherit
+pub fn element_namespace(tag: &str, parent: Option<&'static str>) -> Option<&'static str> {
+    match parent {
+        Some(parent) => Some(parent),
+        None if tag.eq_ignore_ascii_case("svg") => Some(SVG_NAMESPACE),
+        None if tag.eq_ignore_ascii_case("math") => Some(MATHML_NAMESPACE),
+        None => None,
+    }
+}
+
+/// the namespace of the children of this element,
+/// the content of a `foreignObject` is back to html
+pub fn children_namespace(tag: &str, namespace: Option<&'static str>) -> Option<&'static str> {
+    if tag == "foreignObject" {
+        None
+    } else {
+        namespace
+    }
+}
+
+/// match the tag in the namespace, svg tags are matched in their proper case,
+/// html tags such as `a` and `style` are also valid inside svg
+pub fn match_tag(tag: &str, namespace: Option<&'static str>) -> Option<&'static str> {
+    match namespace {
+        Some(SVG_NAMESPACE) => lookup::match_svg_tag(tag).or_else(|| lookup::match_tag(tag)),
+        Some(MATHML_NAMESPACE) => MATHML_TAGS.iter().find(|t| **t == tag).copied(),
+        _ => lookup::match_tag(tag),
+    }
+}
+
+/// match the attribute in the namespace along with the namespace of the attribute itself,
+/// such as the `xlink:href` in svg
+pub fn match_attribute(
+    key: &str,
+    namespace: Option<&'static str>,
+) -> Option<(Option<&'static str>, AttributeName)> {
+    match namespace {
+        Some(SVG_NAMESPACE) => {
+            if let Some(local) = key.strip_prefix("xlink:") {
+                lookup::match_attribute(local)
+                    .map(|_| (Some(XLINK_NAMESPACE), AttributeName::Owned(key.to_string())))
             } else {
-                log::error!("invalid tag: {}", tag_name);
-                Err(ParseError::InvalidTag(tag_name))
+                lookup::match_svg_attribute(key)
+                    .or_else(|| match_html_attribute(key))
+                    .map(|key| (None, AttributeName::Borrowed(key)))
             }
         }
-        NodeType::Text => {
-            let content = content.expect("must have a content");
-            Ok(Some(text(content)))
+        // there is no lookup for mathml attributes, they are all kept as is
+        Some(MATHML_NAMESPACE) => Some((None, AttributeName::Owned(key.to_string()))),
+        _ => match_html_attribute(key).map(|key| (None, AttributeName::Borrowed(key))),
+    }
+}
+
+/// match the html attribute, `style` is not in the attribute lookup
+/// since it is created with its own `style` function
+fn match_html_attribute(key: &str) -> Option<&'static str> {
+    lookup::match_attribute(key).or_else(|| (key == "style").then_some("style"))
+}
+
+/// decode the html entities in the text content.
+/// Entities which decode into a character that is significant in html such as `&lt;`
+/// are kept as a symbol in their original spelling, since text is rendered as is,
+/// the other entities such as `&#9650;` are decoded into the text.
+fn decode_text<MSG>(content: &str) -> Vec<Node<MSG>> {
+    let mut nodes = vec![];
+    let mut buffer = String::new();
+    let mut rest = content;
+    while let Some(start) = rest.find('&') {
+        buffer.push_str(&rest[..start]);
+        rest = &rest[start..];
+        match entity_at(rest) {
+            Some((entity, decoded)) => {
+                if matches!(decoded.as_str(), "<" | ">" | "&" | "\"" | "'") {
+                    if !buffer.is_empty() {
+                        nodes.push(text(std::mem::take(&mut buffer)));
+                    }
+                    nodes.push(Node::Leaf(Leaf::Symbol(entity.to_string().into())));
+                } else {
+                    buffer.push_str(&decoded);
+                }
+                rest = &rest[entity.len()..];
+            }
+            None => {
+                buffer.push('&');
+                rest = &rest[1..];
+            }
         }
-        NodeType::AbstractRoot => {
-            let child_nodes_len = child_nodes.len();
-            match child_nodes_len {
-                0 => Ok(Some(node_list([]))),
-                1 => Ok(Some(child_nodes.remove(0))),
-                _ => Ok(Some(node_list(child_nodes))),
+    }
+    buffer.push_str(rest);
+    if !buffer.is_empty() || nodes.is_empty() {
+        nodes.push(text(buffer));
+    }
+    nodes
+}
+
+/// the longest entity name is `&CounterClockwiseContourIntegral;`
+const MAX_ENTITY_LEN: usize = 33;
+
+/// the entity at the start of this text along with its decoded value,
+/// None if the `&` does not start a valid entity
+fn entity_at(s: &str) -> Option<(&str, String)> {
+    let (end, _) = s
+        .char_indices()
+        .take(MAX_ENTITY_LEN)
+        .skip(1)
+        .find(|(_, c)| !c.is_ascii_alphanumeric() && *c != '#')?;
+    if !s[end..].starts_with(';') {
+        return None;
+    }
+    let entity = &s[..=end];
+    let decoded = html_escape::decode_html_entities(entity);
+    if decoded == entity {
+        None
+    } else {
+        Some((entity, decoded.into_owned()))
+    }
+}
+
+/// split the declarations of an inline style into styles, such as `color: red; margin: 0 4px`.
+/// The property names are resolved to their css name and the whitespace in the values
+/// are collapsed, so they are equal to the styles created with `styles([..])`
+pub(crate) fn parse_style(css: &str) -> Vec<Style> {
+    split_outside_quotes(css, ';')
+        .into_iter()
+        .filter_map(|declaration| {
+            let (property, value) = declaration.split_once(':')?;
+            let property = property.trim();
+            // custom properties are case sensitive, ie: `--main-color`
+            let property = if property.starts_with("--") {
+                property.to_string()
+            } else {
+                property.to_ascii_lowercase()
+            };
+            let property = lookup::match_property(&property)
+                .map(|css_name| css_name.to_string())
+                .unwrap_or(property);
+            let value = collapse_whitespace(value);
+            (!property.is_empty() && !value.is_empty()).then(|| Style::new(property, value))
+        })
+        .collect()
+}
+
+/// split the text by the separator, except where the separator is inside quotes or parenthesis
+/// such as in `url("a;b")`
+fn split_outside_quotes(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = vec![];
+    let mut quote = None;
+    let mut depth = 0;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => (),
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, c) if c == separator && depth <= 0 => {
+                parts.push(&text[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => (),
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// trim and collapse the runs of whitespace outside of quotes into a single space
+fn collapse_whitespace(text: &str) -> String {
+    let mut collapsed = String::new();
+    let mut quote = None;
+    for c in text.trim().chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => (),
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c.is_whitespace() => {
+                if !collapsed.ends_with(' ') {
+                    collapsed.push(' ');
+                }
+                continue;
             }
+            None => (),
         }
-        _ => Ok(None),
+        collapsed.push(c);
     }
+    collapsed
+}
+
+/// the content of `<script>` and `<style>` is not html, it is kept as is
+fn is_raw_text_element(tag: &str, namespace: Option<&'static str>) -> bool {
+    namespace.is_none() && matches!(tag, "script" | "style")
+}
+
+/// the content of `<textarea>` and `<title>` is text where the entities are decoded,
+/// but the tags are never parsed
+fn is_rcdata_element(tag: &str, namespace: Option<&'static str>) -> bool {
+    namespace.is_none() && matches!(tag, "textarea" | "title")
+}
+
+/// the text content of a raw text or rcdata element,
+/// which the parser stores in the element itself instead of in its children
+fn element_text_content(node: &rphtml::parser::Node) -> String {
+    node.content
+        .as_ref()
+        .map(|content| String::from_iter(content.iter()))
+        .unwrap_or_default()
+}
+
+/// the content of a raw text element as a single text, without decoding the entities
+fn raw_text_children<MSG>(node: &rphtml::parser::Node) -> Vec<Node<MSG>> {
+    let content = element_text_content(node);
+    if content.is_empty() {
+        vec![]
+    } else {
+        vec![text(content)]
+    }
+}
+
+/// the content of an rcdata element as text, where the entities are decoded.
+/// The `<`, `>` and `&` in the content are kept as symbols, so the content is never
+/// rendered as markup even when it is moved out of its element
+fn rcdata_children<MSG>(node: &rphtml::parser::Node) -> Vec<Node<MSG>> {
+    let content = element_text_content(node);
+    if content.is_empty() {
+        vec![]
+    } else {
+        decode_text(&content)
+            .into_iter()
+            .flat_map(|node| match node {
+                Node::Leaf(Leaf::Text(content)) => escaped_text(&content),
+                node => vec![node],
+            })
+            .collect()
+    }
+}
+
+/// the text where `<`, `>` and `&` are split into symbols,
+/// since the text is rendered as is
+pub(crate) fn escaped_text<MSG>(content: &str) -> Vec<Node<MSG>> {
+    let mut nodes = vec![];
+    let mut rest = content;
+    while let Some(start) = rest.find(['<', '>', '&']) {
+        if start > 0 {
+            nodes.push(text(&rest[..start]));
+        }
+        let entity = match &rest[start..=start] {
+            "<" => "&lt;",
+            ">" => "&gt;",
+            _ => "&amp;",
+        };
+        nodes.push(Node::Leaf(Leaf::Symbol(entity.into())));
+        rest = &rest[start + 1..];
+    }
+    if !rest.is_empty() || nodes.is_empty() {
+        nodes.push(text(rest));
+    }
+    nodes
+}
+
+/// the name of the doctype such as `html` in `<!DOCTYPE html>`
+fn doctype_name(node: &rphtml::parser::Node) -> String {
+    if let Some(content) = &node.content {
+        let content = String::from_iter(content.iter());
+        let content = content.trim();
+        let name = match content.get(..7) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("doctype") => &content[7..],
+            _ => content,
+        };
+        return name.trim().to_string();
+    }
+    // the doctype is parsed like a tag, where the name is an attribute, ie: `html`
+    let Some(meta) = &node.meta else {
+        return String::new();
+    };
+    let meta = meta.borrow();
+    let words: Vec<String> = meta
+        .attrs
+        .iter()
+        .filter_map(|attr| attr.key.as_ref().or(attr.value.as_ref()))
+        .map(|word| String::from_iter(word.content.iter()))
+        .collect();
+    words.join(" ")
+}
+
+/// a valid custom element name starts with a lowercase letter and has a `-`, ie: `my-widget`
+fn is_custom_element(tag: &str) -> bool {
+    tag.starts_with(|c: char| c.is_ascii_lowercase()) && tag.contains('-')
+}
+
+/// `data-*` and `aria-*` attributes are not in the lookup tables
+fn is_custom_attribute(key: &str) -> bool {
+    let key = key.to_ascii_lowercase();
+    (key.starts_with("data-") || key.starts_with("aria-")) && key.len() > 5
 }
@@ -0,0 +1,494 @@
+//! Sanitize html from untrusted sources such as user submitted rich text.
+//! Only the tags, attributes, url schemes and css properties allowed by the [`Policy`]
+//! are kept, everything else is stripped and reported.
+use crate::{escaped_text, parse_html_with_diagnostics, parse_style, Diagnostic, Mode, ParseError};
+use sauron_core::{
+    html::{html_element, node_list},
+    vdom::{Attribute, AttributeValue, Element, Leaf, Node, Style},
+};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// the attributes which contain a url, their scheme is checked against the policy
+const URL_ATTRIBUTES: &[&str] = &[
+    "href",
+    "src",
+    "srcset",
+    "cite",
+    "action",
+    "formaction",
+    "poster",
+    "background",
+    "xlink:href",
+];
+
+/// the elements which are stripped along with their content, since their content is not
+/// meant to be displayed as text
+const CONTENT_STRIPPED_TAGS: &[&str] = &[
+    "script", "style", "template", "iframe", "object", "embed", "noscript", "svg", "math",
+];
+
+/// attributes allowed in every element, in the default policy
+const DEFAULT_GLOBAL_ATTRIBUTES: &[&str] = &["title", "lang", "dir"];
+
+/// tags allowed in the default policy
+const DEFAULT_TAGS: &[&str] = &[
+    "a",
+    "abbr",
+    "b",
+    "blockquote",
+    "br",
+    "code",
+    "del",
+    "div",
+    "em",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "hr",
+    "i",
+    "img",
+    "ins",
+    "li",
+    "ol",
+    "p",
+    "pre",
+    "q",
+    "s",
+    "small",
+    "span",
+    "strong",
+    "sub",
+    "sup",
+    "table",
+    "tbody",
+    "td",
+    "th",
+    "thead",
+    "tr",
+    "u",
+    "ul",
+];
+
+/// attributes allowed per tag in the default policy
+const DEFAULT_TAG_ATTRIBUTES: &[(&str, &[&str])] = &[
+    ("a", &["href"]),
+    ("img", &["src", "alt", "width", "height"]),
+    ("blockquote", &["cite"]),
+    ("q", &["cite"]),
+    ("ol", &["start"]),
+    ("td", &["colspan", "rowspan"]),
+    ("th", &["colspan", "rowspan"]),
+];
+
+/// url schemes allowed in the default policy, relative urls are always allowed
+const DEFAULT_URL_SCHEMES: &[&str] = &["http", "https", "mailto"];
+
+/// what is allowed to be kept when sanitizing html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Policy {
+    tags: BTreeSet<&'static str>,
+    global_attributes: BTreeSet<&'static str>,
+    tag_attributes: BTreeMap<&'static str, BTreeSet<&'static str>>,
+    url_schemes: BTreeSet<&'static str>,
+    style_properties: BTreeSet<&'static str>,
+}
+
+impl Default for Policy {
+    /// a conservative policy which allows basic text formatting, links, images, lists and tables.
+    /// Links and images are restricted to `http`, `https` and `mailto` urls,
+    /// and inline styles are not allowed.
+    fn default() -> Self {
+        let mut policy = Self::new()
+            .allow_tags(DEFAULT_TAGS.iter().copied())
+            .allow_global_attributes(DEFAULT_GLOBAL_ATTRIBUTES.iter().copied())
+            .allow_url_schemes(DEFAULT_URL_SCHEMES.iter().copied());
+        for (tag, attributes) in DEFAULT_TAG_ATTRIBUTES {
+            policy = policy.allow_attributes(tag, attributes.iter().copied());
+        }
+        policy
+    }
+}
+
+impl Policy {
+    /// a policy which allows nothing, only the text content is kept
+    pub fn new() -> Self {
+        Self {
+            tags: BTreeSet::new(),
+            global_attributes: BTreeSet::new(),
+            tag_attributes: BTreeMap::new(),
+            url_schemes: BTreeSet::new(),
+            style_properties: BTreeSet::new(),
+        }
+    }
+
+    /// allow these tags
+    pub fn allow_tags(mut self, tags: impl IntoIterator<Item = &'static str>) -> Self {
+        self.tags.extend(tags);
+        self
+    }
+
+    /// allow these attributes in every allowed tag
+    pub fn allow_global_attributes(
+        mut self,
+        attributes: impl IntoIterator<Item = &'static str>,
+    ) -> Self {
+        self.global_attributes.extend(attributes);
+        self
+    }
+
+    /// allow these attributes in the tag
+    pub fn allow_attributes(
+        mut self,
+        tag: &'static str,
+        attributes: impl IntoIterator<Item = &'static str>,
+    ) -> Self {
+        self.tag_attributes
+            .entry(tag)
+            .or_default()
+            .extend(attributes);
+        self
+    }
+
+    /// allow urls with these schemes, ie: `https`
+    pub fn allow_url_schemes(mut self, schemes: impl IntoIterator<Item = &'static str>) -> Self {
+        self.url_schemes.extend(schemes);
+        self
+    }
+
+    /// allow these css properties in the `style` attribute,
+    /// the `style` attribute itself has to be allowed too
+    pub fn allow_style_properties(
+        mut self,
+        properties: impl IntoIterator<Item = &'static str>,
+    ) -> Self {
+        self.style_properties.extend(properties);
+        self
+    }
+
+    fn is_tag_allowed(&self, tag: &str) -> bool {
+        self.tags.contains(tag)
+    }
+
+    fn is_attribute_allowed(&self, tag: &str, attribute: &str) -> bool {
+        // event handlers are never allowed
+        if attribute.to_ascii_lowercase().starts_with("on") {
+            return false;
+        }
+        self.global_attributes.contains(attribute)
+            || self
+                .tag_attributes
+                .get(tag)
+                .is_some_and(|attributes| attributes.contains(attribute))
+    }
+
+    /// relative urls are allowed, absolute urls only if their scheme is allowed
+    fn is_url_allowed(&self, url: &str) -> bool {
+        // browsers ignore whitespace and control characters in the scheme, ie: `java\tscript:`
+        let url: String = url
+            .chars()
+            .filter(|c| !c.is_whitespace() && !c.is_control())
+            .collect();
+        let scheme_end = url.find([':', '/', '?', '#']);
+        match scheme_end {
+            Some(end) if url[end..].starts_with(':') => {
+                let scheme = url[..end].to_ascii_lowercase();
+                self.url_schemes.contains(scheme.as_str())
+            }
+            _ => true,
+        }
+    }
+
+    /// every url in the candidates of a `srcset` has to be allowed, ie: `a.png 1x, b.png 2x`
+    fn is_srcset_allowed(&self, srcset: &str) -> bool {
+        srcset
+            .split(',')
+            .filter_map(|candidate| candidate.split_whitespace().next())
+            .all(|url| self.is_url_allowed(url))
+    }
+
+    fn is_style_allowed(&self, property: &str, value: &str) -> bool {
+        // the css escapes are decoded first, ie: `\75rl(` is `url(`
+        let value = decode_css_escapes(value).to_ascii_lowercase();
+        self.style_properties.contains(property)
+            && !value.contains("url(")
+            && !value.contains("expression(")
+    }
+}
+
+/// decode the css escapes such as `\75` or `\u`, which are resolved by the browser
+fn decode_css_escapes(value: &str) -> String {
+    let mut decoded = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            decoded.push(c);
+            continue;
+        }
+        let mut hex = String::new();
+        while hex.len() < 6 {
+            match chars.peek() {
+                Some(digit) if digit.is_ascii_hexdigit() => {
+                    hex.push(*digit);
+                    chars.next();
+                }
+                _ => break,
+            }
+        }
+        if hex.is_empty() {
+            // any other escaped character is the character itself
+            if let Some(escaped) = chars.next() {
+                decoded.push(escaped);
+            }
+        } else {
+            // a single whitespace after the hex digits is part of the escape
+            if chars.peek().is_some_and(|c| c.is_whitespace()) {
+                chars.next();
+            }
+            let code = u32::from_str_radix(&hex, 16).expect("must be hex digits");
+            decoded.push(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+        }
+    }
+    decoded
+}
+
+/// something which was removed when sanitizing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Stripped {
+    /// the tag is not allowed, its content is kept unless it is a tag such as `script`
+    Tag(String),
+    /// the attribute is not allowed in the tag
+    Attribute {
+        /// the tag of the element
+        tag: String,
+        /// the attribute name
+        attribute: String,
+    },
+    /// the url has a scheme which is not allowed, ie: `javascript:`
+    Url {
+        /// the tag of the element
+        tag: String,
+        /// the attribute name
+        attribute: String,
+        /// the url
+        url: String,
+    },
+    /// the css property is not allowed
+    Style {
+        /// the tag of the element
+        tag: String,
+        /// the css property
+        property: String,
+    },
+    /// comments are always removed
+    Comment,
+}
+
+impl fmt::Display for Stripped {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Tag(tag) => write!(f, "tag `{tag}`"),
+            Self::Attribute { tag, attribute } => write!(f, "attribute `{attribute}` in `{tag}`"),
+            Self::Url {
+                tag,
+                attribute,
+                url,
+            } => {
+                write!(f, "url `{url}` in the `{attribute}` of `{tag}`")
+            }
+            Self::Style { tag, property } => write!(f, "css property `{property}` in `{tag}`"),
+            Self::Comment => write!(f, "comment"),
+        }
+    }
+}
+
+/// the sanitized node along with the report of what was stripped
+#[derive(Debug)]
+pub struct Sanitized<MSG> {
+    /// the node with only the allowed parts
+    pub node: Node<MSG>,
+    /// what was removed, in the order they are encountered
+    pub stripped: Vec<Stripped>,
+}
+
+/// parse the html then sanitize it with the policy
+/// ```rust
+/// use sauron_html_parser::sanitize::{sanitize_html, Policy};
+///
+/// let html = r#"<p onclick="steal()">Hi <a href="javascript:steal()">there</a><script>steal()</script></p>"#;
+/// let sanitized = sanitize_html::<()>(html, &Policy::default()).unwrap();
+/// assert_eq!(sanitized.node.render_to_string(), "<p>Hi <a>there</a></p>");
+/// assert_eq!(sanitized.stripped.len(), 3);
+/// ```
+pub fn sanitize_html<MSG>(html: &str, policy: &Policy) -> Result<Sanitized<MSG>, ParseError> {
+    let parsed = parse_html_with_diagnostics(html, Mode::Lenient)?;
+    let node = parsed.node.unwrap_or_else(|| node_list([]));
+    let mut sanitized = sanitize(&node, policy);
+    // the unknown tags and attributes are already dropped by the parser
+    let dropped = parsed
+        .diagnostics
+        .into_iter()
+        .filter_map(|diagnostic| match diagnostic {
+            Diagnostic::UnknownTag { tag, .. } => Some(Stripped::Tag(tag)),
+            Diagnostic::UnknownAttribute { tag, attribute, .. } => {
+                Some(Stripped::Attribute { tag, attribute })
+            }
+            Diagnostic::UnclosedTag { .. } => None,
+        });
+    sanitized.stripped.splice(0..0, dropped);
+    Ok(sanitized)
+}
+
+/// sanitize the node with the policy
+pub fn sanitize<MSG>(node: &Node<MSG>, policy: &Policy) -> Sanitized<MSG> {
+    let mut stripped = vec![];
+    let mut nodes = sanitize_node(node, policy, &mut stripped);
+    let node = if nodes.len() == 1 {
+        nodes.remove(0)
+    } else {
+        node_list(nodes)
+    };
+    Sanitized { node, stripped }
+}
+
+/// a node which is not allowed may still have children that are allowed
+fn sanitize_node<MSG>(
+    node: &Node<MSG>,
+    policy: &Policy,
+    stripped: &mut Vec<Stripped>,
+) -> Vec<Node<MSG>> {
+    match node {
+        Node::Element(elm) => {
+            let tag: &str = elm.tag();
+            if CONTENT_STRIPPED_TAGS.contains(&tag) && !policy.is_tag_allowed(tag) {
+                stripped.push(Stripped::Tag(tag.to_string()));
+                return vec![];
+            }
+            let children = sanitize_children(elm.children(), policy, stripped);
+            if policy.is_tag_allowed(tag) {
+                let attributes = sanitize_attributes(elm, policy, stripped);
+                vec![html_element(
+                    elm.namespace,
+                    elm.tag.clone(),
+                    attributes,
+                    children,
+                    elm.self_closing,
+                )]
+            } else {
+                stripped.push(Stripped::Tag(tag.to_string()));
+                children
+            }
+        }
+        Node::Leaf(leaf) => match leaf {
+            // the text is rendered as is, the markup characters in it are escaped,
+            // ie: the content of an unwrapped `<textarea>`
+            Leaf::Text(text) => escaped_text(text),
+            Leaf::Symbol(_) => vec![node.clone()],
+            Leaf::Comment(_) => {
+                stripped.push(Stripped::Comment);
+                vec![]
+            }
+            Leaf::NodeList(nodes) | Leaf::Fragment(nodes) => {
+                sanitize_children(nodes, policy, stripped)
+            }
+            // doctype and components are not user content
+            _ => vec![],
+        },
+    }
+}
+
+fn sanitize_children<MSG>(
+    nodes: &[Node<MSG>],
+    policy: &Policy,
+    stripped: &mut Vec<Stripped>,
+) -> Vec<Node<MSG>> {
+    nodes
+        .iter()
+        .flat_map(|node| sanitize_node(node, policy, stripped))
+        .collect()
+}
+
+fn sanitize_attributes<MSG>(
+    elm: &Element<MSG>,
+    policy: &Policy,
+    stripped: &mut Vec<Stripped>,
+) -> Vec<Attribute<MSG>> {
+    let tag: &str = elm.tag();
+    let mut attributes = vec![];
+    for attr in elm.attributes() {
+        let name: &str = attr.name();
+        if !policy.is_attribute_allowed(tag, name) {
+            stripped.push(Stripped::Attribute {
+                tag: tag.to_string(),
+                attribute: name.to_string(),
+            });
+            continue;
+        }
+        let mut values = vec![];
+        for value in attr.value() {
+            match value {
+                AttributeValue::Simple(value) if URL_ATTRIBUTES.contains(&name) => {
+                    let url = value.to_string();
+                    let is_allowed = if name == "srcset" {
+                        policy.is_srcset_allowed(&url)
+                    } else {
+                        policy.is_url_allowed(&url)
+                    };
+                    if is_allowed {
+                        values.push(AttributeValue::Simple(value.clone()));
+                    } else {
+                        stripped.push(Stripped::Url {
+                            tag: tag.to_string(),
+                            attribute: name.to_string(),
+                            url,
+                        });
+                    }
+                }
+                AttributeValue::Simple(value) if name == "style" => {
+                    let styles = parse_style(&value.to_string());
+                    values.extend(sanitize_styles(tag, styles, policy, stripped));
+                }
+                AttributeValue::Style(styles) => {
+                    values.extend(sanitize_styles(tag, styles.clone(), policy, stripped));
+                }
+                AttributeValue::Simple(_) | AttributeValue::Empty => values.push(value.clone()),
+                // event listeners are not user content
+                _ => (),
+            }
+        }
+        if !values.is_empty() {
+            attributes.push(Attribute::with_multiple_values(
+                attr.namespace().copied(),
+                attr.name.clone(),
+                values,
+            ));
+        }
+    }
+    attributes
+}
+
+/// the allowed styles, None if none of the styles is allowed
+fn sanitize_styles<MSG>(
+    tag: &str,
+    styles: Vec<Style>,
+    policy: &Policy,
+    stripped: &mut Vec<Stripped>,
+) -> Option<AttributeValue<MSG>> {
+    let styles: Vec<Style> = styles
+        .into_iter()
+        .filter(|style| {
+            let is_allowed = policy.is_style_allowed(&style.name, &style.value.to_string());
+            if !is_allowed {
+                stripped.push(Stripped::Style {
+                    tag: tag.to_string(),
+                    property: style.name.to_string(),
+                });
+            }
+            is_allowed
+        })
+        .collect();
+    (!styles.is_empty()).then(|| AttributeValue::from_styles(styles))
+}
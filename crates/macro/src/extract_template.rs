@@ -0,0 +1,109 @@
+//! extract the static skeleton of the view, which is the view with only the literal parts.
+//! Blocks are replaced with a comment placeholder, so the skeleton has the same number of
+//! nodes as the view, and the dynamic attributes are left out.
+use crate::extract_skip_diff::is_literal_attribute;
+use proc_macro2::TokenStream;
+use quote::quote;
+use rstml::node::{KeyedAttributeValue, Node, NodeAttribute};
+use sauron_core::html::lookup;
+
+pub fn to_token_stream(input: proc_macro::TokenStream) -> TokenStream {
+    match rstml::parse(input) {
+        Ok(nodes) => multiple_nodes(&nodes),
+        Err(error) => error.to_compile_error(),
+    }
+}
+
+fn multiple_nodes(nodes: &[Node]) -> TokenStream {
+    let only_one_node = nodes.len() == 1;
+    if only_one_node {
+        single_node(&nodes[0])
+    } else {
+        let children_tokens = nodes_to_tokens(nodes);
+        quote! {
+            sauron::html::node_list([
+                #children_tokens
+            ])
+        }
+    }
+}
+
+fn single_node(node: &Node) -> TokenStream {
+    match node {
+        Node::Element(elm) => {
+            let tag = elm.open_tag.name.to_string();
+            let self_closing = lookup::is_self_closing(&tag);
+            let namespace = lookup::tag_namespace(&tag);
+            let attributes = literal_attributes(&elm.open_tag.attributes);
+            let children = nodes_to_tokens(&elm.children);
+            let ns = if let Some(namespace) = namespace {
+                quote! { Some(#namespace) }
+            } else {
+                quote! { None }
+            };
+            quote! {
+                sauron::html::element_ns(#ns, #tag, [#attributes], [#children], #self_closing)
+            }
+        }
+        Node::Fragment(fragment) => multiple_nodes(&fragment.children),
+        Node::Text(node_text) => {
+            let text = node_text.value_string();
+            quote! {
+                sauron::Node::Leaf(sauron::vdom::Leaf::Text(#text.into()))
+            }
+        }
+        Node::RawText(raw_text) => {
+            let text = raw_text.to_token_stream_string();
+            quote! {
+                sauron::Node::Leaf(sauron::vdom::Leaf::Text(#text.into()))
+            }
+        }
+        Node::Comment(comment) => {
+            let comment_text = comment.value.value();
+            quote! {
+                sauron::Node::Leaf(sauron::vdom::Leaf::Comment(#comment_text.into()))
+            }
+        }
+        Node::Doctype(doctype) => {
+            let value = doctype.value.to_token_stream_string();
+            quote! {
+                sauron::Node::Leaf(sauron::vdom::Leaf::DocType(#value.into()))
+            }
+        }
+        // the dynamic part of the view, which will be patched in after the template is cloned
+        Node::Block(_) => {
+            quote! {
+                sauron::Node::Leaf(sauron::vdom::Leaf::Comment("".into()))
+            }
+        }
+    }
+}
+
+fn nodes_to_tokens(nodes: &[Node]) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    for node in nodes {
+        let node_token = single_node(node);
+        tokens.extend(quote! {
+            #node_token,
+        });
+    }
+    tokens
+}
+
+/// only the attributes with literal values are part of the template
+fn literal_attributes(attributes: &[NodeAttribute]) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    for attribute in attributes.iter().filter(|a| is_literal_attribute(a)) {
+        let NodeAttribute::Attribute(attribute) = attribute else {
+            continue;
+        };
+        let attr = attribute.key.to_string();
+        if let KeyedAttributeValue::Value(value) = &attribute.possible_value {
+            let value = &value.value;
+            tokens.extend(quote! {
+                sauron::html::attributes::attr(#attr, #value),
+            });
+        }
+    }
+    tokens
+}
@@ -0,0 +1,341 @@
+//! compile html templates into the equivalent node construction code
+//!
+//! The html is parsed at compile time, placeholders are used to bind rust expressions
+//! - `{{ expr }}` in text and attribute values is interpolated as text
+//! - `{{{ expr }}}` in text is inserted as a child node
+//! - `on_click="{{ expr }}"` or `on:click="{{ expr }}"` attaches an event listener
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use sauron_core::html::lookup;
+use sauron_html_parser::{
+    children_namespace, element_namespace, match_attribute, match_tag,
+    rphtml::parser::{Node, NodeType},
+    Lines, Position,
+};
+use std::ops::Deref;
+use syn::{Expr, LitStr, Result};
+
+/// a segment of a text or attribute value
+enum Part {
+    /// literal text
+    Text(String),
+    /// `{{ expr }}`
+    Expr(Expr),
+    /// `{{{ expr }}}`
+    Node(Expr),
+}
+
+/// `html_template!("<div>{{ self.name }}</div>")`
+pub fn to_token_stream(input: proc_macro::TokenStream) -> TokenStream {
+    let html = syn::parse::<LitStr>(input);
+    match html.and_then(|html| template_to_tokens(&html.value(), html.span())) {
+        Ok(tokens) => tokens,
+        Err(error) => error.to_compile_error(),
+    }
+}
+
+/// `include_html!("templates/card.html")`, the path is relative to the crate root
+pub fn include_to_token_stream(input: proc_macro::TokenStream) -> TokenStream {
+    match syn::parse::<LitStr>(input).and_then(include_html) {
+        Ok(tokens) => tokens,
+        Err(error) => error.to_compile_error(),
+    }
+}
+
+fn include_html(path: LitStr) -> Result<TokenStream> {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default();
+    let full_path = std::path::Path::new(&manifest_dir).join(path.value());
+    let html = std::fs::read_to_string(&full_path).map_err(|e| {
+        syn::Error::new(
+            path.span(),
+            format!("unable to read {}: {e}", full_path.display()),
+        )
+    })?;
+    let node = template_to_tokens(&html, path.span())?;
+    let full_path = full_path.display().to_string();
+    // include the file, so the crate is recompiled when the template changes
+    Ok(quote! {
+        {
+            const _: &str = include_str!(#full_path);
+            #node
+        }
+    })
+}
+
+fn template_to_tokens(html: &str, span: Span) -> Result<TokenStream> {
+    let doc = sauron_html_parser::parse_doc_strict(html)
+        .map_err(|e| syn::Error::new(span, format!("malformed html template: {e}")))?;
+    let template = Template {
+        span,
+        lines: Lines::new(html),
+    };
+    let root = doc.get_root_node();
+    let mut nodes = template.node_to_tokens(root.borrow().deref(), None)?;
+    if nodes.len() == 1 {
+        Ok(nodes.remove(0))
+    } else {
+        Ok(quote! {
+            sauron::html::node_list([#(#nodes,)*])
+        })
+    }
+}
+
+/// the html template being compiled, the errors are all reported on the span of the template
+/// with the line and column of where the error is in the html
+struct Template {
+    span: Span,
+    lines: Lines,
+}
+
+impl Template {
+    fn error(&self, position: Position, message: String) -> syn::Error {
+        syn::Error::new(self.span, format!("{position}: {message}"))
+    }
+
+    fn children_to_tokens(
+        &self,
+        node: &Node,
+        namespace: Option<&'static str>,
+    ) -> Result<Vec<TokenStream>> {
+        let mut children = vec![];
+        if let Some(childs) = &node.childs {
+            for child in childs {
+                children.extend(self.node_to_tokens(child.borrow().deref(), namespace)?);
+            }
+        }
+        Ok(children)
+    }
+
+    /// the `namespace` is the namespace of the parent element, the same as the html parser
+    fn node_to_tokens(
+        &self,
+        node: &Node,
+        namespace: Option<&'static str>,
+    ) -> Result<Vec<TokenStream>> {
+        let content = node
+            .content
+            .as_ref()
+            .map(|content| String::from_iter(content.iter()));
+
+        match node.node_type {
+            NodeType::AbstractRoot => self.children_to_tokens(node, namespace),
+            NodeType::Tag => {
+                let meta = node.meta.as_ref().expect("must have a tag").borrow();
+                let tag_name = String::from_iter(meta.name.iter());
+                let namespace = element_namespace(&tag_name, namespace);
+                let is_custom_element = namespace.is_none() && tag_name.contains('-');
+                let Some(tag) = match_tag(&tag_name, namespace)
+                    .map(str::to_string)
+                    .or_else(|| is_custom_element.then(|| tag_name.clone()))
+                else {
+                    return Err(self.error(
+                        self.lines.position(node.begin_at),
+                        format!("invalid tag in html template: <{tag_name}>"),
+                    ));
+                };
+                let self_closing = namespace.is_none() && lookup::is_self_closing(&tag);
+                let ns = match namespace {
+                    Some(namespace) => quote! { Some(#namespace) },
+                    None => quote! { None },
+                };
+                let mut attributes = vec![];
+                for attr in meta.attrs.iter() {
+                    if let Some(key) = &attr.key {
+                        let name = String::from_iter(key.content.iter());
+                        let value = attr
+                            .value
+                            .as_ref()
+                            .map(|value| String::from_iter(value.content.iter()));
+                        let position = self.lines.attribute_position(node, &name);
+                        attributes.push(self.attribute_to_tokens(
+                            &name,
+                            value.as_deref(),
+                            namespace,
+                            position,
+                        )?);
+                    }
+                }
+                let children =
+                    self.children_to_tokens(node, children_namespace(&tag, namespace))?;
+                Ok(vec![quote! {
+                    sauron::html::element_ns(#ns, #tag, [#(#attributes,)*], [#(#children,)*], #self_closing)
+                }])
+            }
+            NodeType::Text => {
+                let content = content.unwrap_or_default();
+                // whitespace in between tags are formatting of the template
+                if content.trim().is_empty() {
+                    return Ok(vec![]);
+                }
+                // consecutive text and `{{ expr }}` are combined into one text node,
+                // while `{{{ expr }}}` are inserted as is
+                let mut nodes = vec![];
+                let mut texts = vec![];
+                let parts = self.parse_parts(&content, |offset| {
+                    self.lines.position(node.begin_at + offset)
+                })?;
+                for part in parts {
+                    match part {
+                        Part::Node(expr) => {
+                            if !texts.is_empty() {
+                                let text = format_parts(std::mem::take(&mut texts));
+                                nodes.push(quote! { sauron::html::text(#text) });
+                            }
+                            nodes.push(quote! { #expr });
+                        }
+                        // whitespace in between nodes are formatting of the template
+                        Part::Text(text) if text.trim().is_empty() && text.contains('\n') => (),
+                        part => texts.push(part),
+                    }
+                }
+                if !texts.is_empty() {
+                    let text = format_parts(texts);
+                    nodes.push(quote! { sauron::html::text(#text) });
+                }
+                Ok(nodes)
+            }
+            NodeType::Comment => {
+                let comment = content.unwrap_or_default();
+                Ok(vec![quote! { sauron::html::comment(#comment) }])
+            }
+            _ => Ok(vec![]),
+        }
+    }
+
+    /// the attribute names are matched in the namespace of the element,
+    /// such as the camelCase `viewBox` and the namespaced `xlink:href` in svg,
+    /// names which are not matched are used as is
+    fn attribute_to_tokens(
+        &self,
+        name: &str,
+        value: Option<&str>,
+        namespace: Option<&'static str>,
+        position: Position,
+    ) -> Result<TokenStream> {
+        let event = name
+            .strip_prefix("on_")
+            .or_else(|| name.strip_prefix("on:"));
+
+        let Some(value) = value else {
+            if event.is_some() {
+                return Err(self.error(
+                    position,
+                    format!(
+                        "event `{name}` requires a handler, ie: {name}=\"{{{{ |_| Msg::Click }}}}\""
+                    ),
+                ));
+            }
+            return Ok(quote! { sauron::html::attributes::attr(#name, true) });
+        };
+        let parts = self.parse_parts(value, |_| position)?;
+        if parts.iter().any(|part| matches!(part, Part::Node(_))) {
+            return Err(self.error(
+                position,
+                format!(
+                    "`{{{{{{ }}}}}}` inserts a node, and can not be used in attribute `{name}`"
+                ),
+            ));
+        }
+
+        if let Some(event) = event {
+            return match parts.as_slice() {
+                [Part::Expr(handler)] => {
+                    let event = format_ident!("on_{event}");
+                    Ok(quote! { sauron::html::events::#event(#handler) })
+                }
+                _ => Err(self.error(
+                    position,
+                    format!("event `{name}` must be a single placeholder, ie: {name}=\"{{{{ |_| Msg::Click }}}}\""),
+                )),
+            };
+        }
+
+        let value = format_parts(parts);
+        match match_attribute(name, namespace) {
+            Some((Some(attr_namespace), name)) => {
+                let name: &str = &name;
+                Ok(quote! { sauron::vdom::attr_ns(Some(#attr_namespace), #name, #value) })
+            }
+            Some((None, name)) => {
+                let name: &str = &name;
+                Ok(quote! { sauron::html::attributes::attr(#name, #value) })
+            }
+            None => Ok(quote! { sauron::html::attributes::attr(#name, #value) }),
+        }
+    }
+
+    /// split the text into literal text and the `{{ expr }}`, `{{{ expr }}}` placeholders,
+    /// `position` is the position of the character at this offset of the text
+    fn parse_parts(&self, text: &str, position: impl Fn(usize) -> Position) -> Result<Vec<Part>> {
+        let mut parts = vec![];
+        let mut rest = text;
+        while let Some(start) = rest.find("{{") {
+            if start > 0 {
+                parts.push(Part::Text(rest[..start].to_string()));
+            }
+            let is_node = rest[start..].starts_with("{{{");
+            let (open, close) = if is_node {
+                ("{{{", "}}}")
+            } else {
+                ("{{", "}}")
+            };
+            // the offset in characters of the placeholder in the text
+            let offset = text[..text.len() - rest.len() + start].chars().count();
+            let inner_start = start + open.len();
+            let Some(len) = rest[inner_start..].find(close) else {
+                return Err(self.error(
+                    position(offset),
+                    format!("unclosed placeholder `{open}` in html template, expecting `{close}`"),
+                ));
+            };
+            let inner = &rest[inner_start..inner_start + len];
+            let expr = syn::parse_str::<Expr>(inner.trim()).map_err(|e| {
+                self.error(
+                    position(offset),
+                    format!("invalid expression in placeholder `{open}{inner}{close}`: {e}"),
+                )
+            })?;
+            parts.push(if is_node {
+                Part::Node(expr)
+            } else {
+                Part::Expr(expr)
+            });
+            rest = &rest[inner_start + len + close.len()..];
+        }
+        if !rest.is_empty() {
+            parts.push(Part::Text(rest.to_string()));
+        }
+        Ok(parts)
+    }
+}
+
+/// a string expression from the text and `{{ expr }}` parts,
+/// literal text are decoded from their html entities
+fn format_parts(parts: Vec<Part>) -> TokenStream {
+    let decode = |text: &str| html_escape::decode_html_entities(text).to_string();
+    match parts.as_slice() {
+        [] => quote! { "" },
+        [Part::Text(text)] => {
+            let text = decode(text);
+            quote! { #text }
+        }
+        [Part::Expr(expr)] => quote! { #expr },
+        _ => {
+            let mut format = String::new();
+            let mut args = vec![];
+            for part in parts {
+                match part {
+                    Part::Text(text) => {
+                        format.push_str(&decode(&text).replace('{', "{{").replace('}', "}}"));
+                    }
+                    Part::Expr(expr) => {
+                        format.push_str("{}");
+                        args.push(expr);
+                    }
+                    Part::Node(_) => unreachable!("node placeholders are not formatted"),
+                }
+            }
+            quote! { format!(#format, #(#args),*) }
+        }
+    }
+}
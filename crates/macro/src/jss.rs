@@ -1,9 +1,12 @@
+use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use syn::parse::{Parse, ParseStream};
-use syn::{Expr, Result, Token};
+use syn::{Expr, ExprLit, Ident, Lit, LitStr, Result, Token};
 
+pub(crate) use scoped::ScopedStyleSheet;
 pub(crate) use style::Style;
 
+mod scoped;
 mod style;
 
 pub(crate) struct StyleSheetWithConditionalGroup {
@@ -12,7 +15,7 @@ pub(crate) struct StyleSheetWithConditionalGroup {
 }
 
 pub(crate) struct StyleSheet {
-    selector_with_styles: Vec<SelectorWithStyle>,
+    rules: Vec<Rule>,
 }
 
 /// ```ignore
@@ -26,40 +29,397 @@ struct SelectorWithStyle {
     style: Style,
 }
 
+/// A rule at the top level of the stylesheet
+enum Rule {
+    /// ```ignore
+    /// ".layer": { <block> }
+    /// ```
+    Selector { selector: Expr, block: Block },
+    /// ```ignore
+    /// "@media screen and (max-width: 800px)": { $(<rule>)* }
+    /// "@supports (display: grid)": { $(<rule>)* }
+    /// ```
+    ConditionalGroup { condition: String, rules: Vec<Rule> },
+    /// ```ignore
+    /// "@keyframes spin": {
+    ///     "0%": { <style> },
+    ///     "100%": { <style> },
+    /// }
+    /// ```
+    Keyframes {
+        keyframes: String,
+        frames: Vec<(String, Style)>,
+    },
+    /// ```ignore
+    /// "@font-face": { <style> }
+    /// ```
+    FontFace { style: Style },
+}
+
+/// The body of a selector which contains the properties of the selector,
+/// and nested rules which are flatten along with the parent selector.
+/// ```ignore
+/// background_color: "red",
+/// "&:hover": {
+///     background_color: "blue",
+/// },
+/// "@media (max-width: 800px)": {
+///     width: "100%",
+/// },
+/// ```
+pub(crate) struct Block {
+    style: Style,
+    nested: Vec<NestedRule>,
+}
+
+enum NestedRule {
+    /// ```ignore
+    /// "&:hover": { <block> }
+    /// "& > .child": { <block> }
+    /// ".child": { <block> }
+    /// ```
+    Selector { selector: LitStr, block: Block },
+    /// ```ignore
+    /// "@media (max-width: 800px)": { <block> }
+    /// ```
+    ConditionalGroup { condition: String, block: Block },
+}
+
+/// The at-rules which can contain rules, and can be nested in a selector
+const CONDITIONAL_GROUP_RULES: &[&str] = &["@media", "@supports", "@container", "@layer"];
+
+fn is_conditional_group(at_rule: &str) -> bool {
+    CONDITIONAL_GROUP_RULES
+        .iter()
+        .any(|rule| at_rule == *rule || at_rule.starts_with(&format!("{rule} ")))
+}
+
+/// A selector that is flatten at compile time if it is a literal,
+/// otherwise it is combined at runtime.
+#[derive(Clone)]
+pub(crate) enum Selector {
+    /// `".layer"`
+    Literal(String),
+    /// `format!(".{}", COMPONENT_NAME)`
+    Dynamic(TokenStream),
+}
+
+impl Selector {
+    /// combine the nested `child` selector with this selector.
+    /// `&` in the child is replaced with the parent selector, if there is no `&`
+    /// the child is treated as a descendant of the parent.
+    pub(crate) fn nest(&self, child: &str) -> Self {
+        match self {
+            Selector::Literal(parent) => Selector::Literal(nest_selector(parent, child)),
+            Selector::Dynamic(parent) => {
+                let children: Vec<String> = child
+                    .split(',')
+                    .map(str::trim)
+                    .map(|child| {
+                        if child.contains('&') {
+                            child.to_string()
+                        } else {
+                            format!("& {child}")
+                        }
+                    })
+                    .collect();
+                // the comma separated selectors of the parent are only known at runtime
+                Selector::Dynamic(quote! {
+                    ::std::string::ToString::to_string(&#parent)
+                        .split(',')
+                        .map(|parent| parent.trim())
+                        .flat_map(|parent| {
+                            [#(#children),*]
+                                .into_iter()
+                                .map(move |child| child.replace('&', parent))
+                        })
+                        .collect::<::std::vec::Vec<_>>()
+                        .join(", ")
+                })
+            }
+        }
+    }
+}
+
+impl ToTokens for Selector {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Selector::Literal(selector) => tokens.extend(quote! {#selector}),
+            Selector::Dynamic(selector) => tokens.extend(selector.clone()),
+        }
+    }
+}
+
+/// combine each of the comma separated selectors of the parent and the child
+fn nest_selector(parent: &str, child: &str) -> String {
+    let mut combined = vec![];
+    for parent in parent.split(',').map(str::trim) {
+        for child in child.split(',').map(str::trim) {
+            if child.contains('&') {
+                combined.push(child.replace('&', parent));
+            } else {
+                combined.push(format!("{parent} {child}"));
+            }
+        }
+    }
+    combined.join(", ")
+}
+
+/// wrap the css of rules inside the at-rule
+fn at_rule_tokens(at_rule: &str, chunks: Vec<TokenStream>) -> TokenStream {
+    quote! {
+        format!("{} {{\n{}\n}}\n", #at_rule, <[String]>::join(&[#(#chunks,)*], "\n"))
+    }
+}
+
+fn style_tokens(selector: impl ToTokens, style: &Style) -> TokenStream {
+    let style = style.to_tokens_with_pretty();
+    quote! {
+        format!("{} {{\n{}\n}}\n", #selector, #style)
+    }
+}
+
 impl Parse for StyleSheet {
-    /// $(<selector> : { <style> } (,)?)*
+    /// $(<rule> (,)?)*
     fn parse(input: ParseStream) -> Result<Self> {
-        let mut selector_with_styles = vec![];
+        let mut rules = vec![];
         while !input.is_empty() {
-            selector_with_styles.push(input.parse()?);
+            rules.push(input.parse()?);
             if input.peek(Token![,]) {
                 input.parse::<Token![,]>()?;
             }
         }
-        Ok(Self {
-            selector_with_styles,
-        })
+        Ok(Self { rules })
+    }
+}
+
+impl Parse for Rule {
+    /// ```ignore
+    /// <selector> : { <block> }
+    /// "@<at-rule>" : { ... }
+    /// ```
+    fn parse(input: ParseStream) -> Result<Self> {
+        let selector = input.parse::<Expr>()?;
+        input.parse::<Token![:]>()?;
+        let inner;
+        syn::braced!(inner in input);
+        let at_rule = match &selector {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(lit), ..
+            }) if lit.value().starts_with('@') => lit,
+            _ => {
+                let block = inner.parse()?;
+                return Ok(Rule::Selector { selector, block });
+            }
+        };
+        let value = at_rule.value();
+        if is_conditional_group(&value) {
+            let mut rules = vec![];
+            while !inner.is_empty() {
+                rules.push(inner.parse()?);
+                if inner.peek(Token![,]) {
+                    inner.parse::<Token![,]>()?;
+                }
+            }
+            Ok(Rule::ConditionalGroup {
+                condition: value,
+                rules,
+            })
+        } else if value.starts_with("@keyframes ") {
+            let mut frames = vec![];
+            while !inner.is_empty() {
+                frames.push(parse_keyframe(&inner)?);
+                if inner.peek(Token![,]) {
+                    inner.parse::<Token![,]>()?;
+                }
+            }
+            Ok(Rule::Keyframes {
+                keyframes: value,
+                frames,
+            })
+        } else if value == "@font-face" {
+            Ok(Rule::FontFace {
+                style: inner.parse()?,
+            })
+        } else {
+            Err(syn::Error::new(
+                at_rule.span(),
+                format!(
+                    "unsupported at-rule: {value}, expecting one of {CONDITIONAL_GROUP_RULES:?}, @keyframes or @font-face"
+                ),
+            ))
+        }
+    }
+}
+
+/// ```ignore
+/// "50%": { <style> }
+/// from: { <style> }
+/// ```
+fn parse_keyframe(input: ParseStream) -> Result<(String, Style)> {
+    let selector = if input.peek(LitStr) {
+        input.parse::<LitStr>()?.value()
+    } else {
+        let ident = input.parse::<Ident>()?;
+        match ident.to_string().as_str() {
+            "from" | "to" => ident.to_string(),
+            _ => {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "expecting `from`, `to` or a percentage such as \"50%\"",
+                ))
+            }
+        }
+    };
+    input.parse::<Token![:]>()?;
+    let inner;
+    syn::braced!(inner in input);
+    Ok((selector, inner.parse()?))
+}
+
+impl Rule {
+    fn to_chunks(&self) -> Vec<TokenStream> {
+        match self {
+            Rule::Selector { selector, block } => {
+                let selector = match selector {
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(lit), ..
+                    }) => Selector::Literal(lit.value()),
+                    _ => Selector::Dynamic(selector.to_token_stream()),
+                };
+                let mut chunks = vec![];
+                block.flatten(&selector, &|s| s.to_string(), &mut chunks);
+                chunks
+            }
+            Rule::ConditionalGroup { condition, rules } => {
+                let chunks = rules.iter().flat_map(|rule| rule.to_chunks()).collect();
+                vec![at_rule_tokens(condition, chunks)]
+            }
+            Rule::Keyframes { keyframes, frames } => {
+                let chunks = frames
+                    .iter()
+                    .map(|(selector, style)| style_tokens(selector, style))
+                    .collect();
+                vec![at_rule_tokens(keyframes, chunks)]
+            }
+            Rule::FontFace { style } => vec![style_tokens("@font-face", style)],
+        }
     }
 }
 
 impl ToTokens for StyleSheet {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        let expanded_selector_with_styles: Vec<_> = self
-            .selector_with_styles
+        let chunks: Vec<_> = self
+            .rules
             .iter()
-            .map(|ss| {
-                quote! {
-                    #ss,
-                }
-            })
+            .flat_map(|rule| rule.to_chunks())
             .collect();
-        let ss_tokens = proc_macro2::TokenStream::from_iter(expanded_selector_with_styles);
         tokens.extend(quote! {
-            [#ss_tokens].join("\n")
+            <[String]>::join(&[#(#chunks,)*], "\n")
         });
     }
 }
 
+impl Parse for Block {
+    /// ```ignore
+    /// $(<property> : <value> | "<nested selector>" : { <block> } (,)?)*
+    /// ```
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut style = Style::default();
+        let mut nested = vec![];
+        while !input.is_empty() {
+            let is_nested =
+                input.peek(LitStr) && input.peek2(Token![:]) && input.peek3(syn::token::Brace);
+            if is_nested {
+                nested.push(input.parse()?);
+                if input.peek(Token![,]) {
+                    input.parse::<Token![,]>()?;
+                }
+            } else {
+                style.parse_property(input)?;
+            }
+        }
+        Ok(Self { style, nested })
+    }
+}
+
+impl Parse for NestedRule {
+    /// ```ignore
+    /// "<selector>" : { <block> }
+    /// "@media <condition>" : { <block> }
+    /// ```
+    fn parse(input: ParseStream) -> Result<Self> {
+        let selector = input.parse::<LitStr>()?;
+        input.parse::<Token![:]>()?;
+        let inner;
+        syn::braced!(inner in input);
+        let block = inner.parse()?;
+        let value = selector.value();
+        if is_conditional_group(&value) {
+            Ok(NestedRule::ConditionalGroup {
+                condition: value,
+                block,
+            })
+        } else if value.starts_with('@') {
+            Err(syn::Error::new(
+                selector.span(),
+                format!(
+                    "{value} can not be nested in a selector, only {CONDITIONAL_GROUP_RULES:?} can be nested"
+                ),
+            ))
+        } else {
+            Ok(NestedRule::Selector { selector, block })
+        }
+    }
+}
+
+impl Block {
+    /// flatten this block and its nested rules into css chunks,
+    /// `scope` is applied to the nested selectors before they are combined with the parent
+    pub(crate) fn flatten(
+        &self,
+        selector: &Selector,
+        scope: &dyn Fn(&str) -> String,
+        chunks: &mut Vec<TokenStream>,
+    ) {
+        // an empty selector is still emitted, for compatibility with flat stylesheets
+        if !self.style.is_empty() || self.nested.is_empty() {
+            chunks.push(style_tokens(selector, &self.style));
+        }
+        for nested in self.nested.iter() {
+            match nested {
+                NestedRule::Selector {
+                    selector: child,
+                    block,
+                } => {
+                    let child = scope(&child.value());
+                    block.flatten(&selector.nest(&child), scope, chunks);
+                }
+                NestedRule::ConditionalGroup { condition, block } => {
+                    let mut inner = vec![];
+                    block.flatten(selector, scope, &mut inner);
+                    chunks.push(at_rule_tokens(condition, inner));
+                }
+            }
+        }
+    }
+
+    /// the selectors of the nested rules, including the deeply nested ones
+    pub(crate) fn nested_selectors(&self) -> Vec<&LitStr> {
+        self.nested
+            .iter()
+            .flat_map(|nested| match nested {
+                NestedRule::Selector { selector, block } => {
+                    let mut selectors = vec![selector];
+                    selectors.extend(block.nested_selectors());
+                    selectors
+                }
+                NestedRule::ConditionalGroup { block, .. } => block.nested_selectors(),
+            })
+            .collect()
+    }
+}
+
 impl Parse for StyleSheetWithConditionalGroup {
     /// <conditional> : { $(<selector> : { <style> } (,)?)* }
     fn parse(input: ParseStream) -> Result<Self> {
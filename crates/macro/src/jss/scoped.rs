@@ -0,0 +1,325 @@
+use super::{Block, Selector};
+use proc_macro2::Span;
+use quote::{quote, quote_spanned, ToTokens};
+use syn::parse::{Parse, ParseStream};
+use syn::{Attribute, Ident, LitStr, Result, Token, Visibility};
+
+/// A stylesheet whose class names are scoped to a generated struct.
+///
+/// ```ignore
+/// struct ButtonStyles;
+///
+/// button: {
+///     background_color: "red",
+/// },
+///
+/// ".button:hover .label": {
+///     color: "white",
+/// },
+/// ```
+pub(crate) struct ScopedStyleSheet {
+    attrs: Vec<Attribute>,
+    vis: Visibility,
+    name: Ident,
+    rules: Vec<ScopedRule>,
+}
+
+/// a selector and its block, where the class names
+/// used in the selector and its nested selectors are replaced with the hashed names
+struct ScopedRule {
+    selector: ScopedSelector,
+    block: Block,
+}
+
+enum ScopedSelector {
+    /// `button` is a shorthand for `".button"`
+    Class(Ident),
+    /// `".button:hover .label"`
+    Selector(LitStr),
+}
+
+impl Parse for ScopedStyleSheet {
+    /// ```ignore
+    /// $(#[<attr>])* <vis> struct <name>;
+    /// $(<selector> : { <style> } (,)?)*
+    /// ```
+    fn parse(input: ParseStream) -> Result<Self> {
+        let attrs = input.call(Attribute::parse_outer)?;
+        let vis = input.parse()?;
+        input.parse::<Token![struct]>()?;
+        let name = input.parse()?;
+        input.parse::<Token![;]>()?;
+        let mut rules = vec![];
+        while !input.is_empty() {
+            rules.push(input.parse()?);
+            if input.peek(Token![,]) {
+                input.parse::<Token![,]>()?;
+            }
+        }
+        if rules.is_empty() {
+            return Err(syn::Error::new(
+                input.span(),
+                "expecting at least one selector in the scoped stylesheet",
+            ));
+        }
+        let scoped = Self {
+            attrs,
+            vis,
+            name,
+            rules,
+        };
+        let classes = scoped.classes();
+        for (i, (class, span)) in classes.iter().enumerate() {
+            let field = field_name(class);
+            field_ident(&field, *span)?;
+            // ie: `.btn-primary` and `.btn_primary` would share the field `btn_primary`
+            if let Some((other, _)) = classes[..i].iter().find(|(c, _)| field_name(c) == field) {
+                return Err(syn::Error::new(
+                    *span,
+                    format!("the classes `.{other}` and `.{class}` are both the field `{field}`"),
+                ));
+            }
+        }
+        Ok(scoped)
+    }
+}
+
+impl Parse for ScopedRule {
+    /// ```ignore
+    /// <ident | "selector"> : { <block> }
+    /// ```
+    fn parse(input: ParseStream) -> Result<Self> {
+        let selector = if input.peek(LitStr) {
+            let selector: LitStr = input.parse()?;
+            if class_names(&selector.value()).is_empty() {
+                return Err(syn::Error::new(
+                    selector.span(),
+                    "scoped selector must use at least one class, ie: `.button`",
+                ));
+            }
+            ScopedSelector::Selector(selector)
+        } else {
+            ScopedSelector::Class(input.parse()?)
+        };
+        input.parse::<Token![:]>()?;
+        let inner;
+        syn::braced!(inner in input);
+        let block = inner.parse()?;
+        Ok(Self { selector, block })
+    }
+}
+
+impl ScopedStyleSheet {
+    /// the class names in the order of their first appearance, along with the span of the
+    /// selector where it is first used, so unused fields are reported in the user's code
+    fn classes(&self) -> Vec<(String, Span)> {
+        let mut classes: Vec<(String, Span)> = vec![];
+        for rule in self.rules.iter() {
+            let mut used = match &rule.selector {
+                ScopedSelector::Class(ident) => vec![(ident.to_string(), ident.span())],
+                ScopedSelector::Selector(selector) => class_names(&selector.value())
+                    .into_iter()
+                    .map(|class| (class, selector.span()))
+                    .collect(),
+            };
+            for nested in rule.block.nested_selectors() {
+                used.extend(
+                    class_names(&nested.value())
+                        .into_iter()
+                        .map(|class| (class, nested.span())),
+                );
+            }
+            for (class, span) in used {
+                if !classes.iter().any(|(c, _)| *c == class) {
+                    classes.push((class, span));
+                }
+            }
+        }
+        classes
+    }
+
+    /// the hashed class name which is unique to the crate, the struct and the content
+    /// of this stylesheet
+    fn hashed_class(&self, class: &str) -> String {
+        let class = field_name(class);
+        let crate_name = std::env::var("CARGO_PKG_NAME").unwrap_or_default();
+        let content = self.rules.iter().fold(String::new(), |acc, rule| {
+            let selector = match &rule.selector {
+                ScopedSelector::Class(ident) => ident.to_string(),
+                ScopedSelector::Selector(selector) => selector.value(),
+            };
+            acc + &selector
+        });
+        let hash = fnv1a([
+            crate_name.as_str(),
+            &self.name.to_string(),
+            &class,
+            &content,
+        ]);
+        format!("{}_{}_{:08x}", self.name, class, hash)
+    }
+
+    fn scoped_selector(&self, selector: &ScopedSelector) -> String {
+        match selector {
+            ScopedSelector::Class(ident) => format!(".{}", self.hashed_class(&ident.to_string())),
+            ScopedSelector::Selector(selector) => {
+                replace_class_names(&selector.value(), |class| self.hashed_class(class))
+            }
+        }
+    }
+}
+
+impl ToTokens for ScopedStyleSheet {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let attrs = &self.attrs;
+        let vis = &self.vis;
+        let name = &self.name;
+        let classes = self.classes();
+        let fields: Vec<Ident> = classes
+            .iter()
+            .map(|(c, span)| field_ident(&field_name(c), *span).expect("checked when parsed"))
+            .collect();
+        let hashed: Vec<String> = classes.iter().map(|(c, _)| self.hashed_class(c)).collect();
+        let docs: Vec<String> = classes
+            .iter()
+            .map(|(c, _)| format!("the scoped class name of `.{c}`"))
+            .collect();
+        let scope = |selector: &str| replace_class_names(selector, |c| self.hashed_class(c));
+        let mut chunks = vec![];
+        for rule in self.rules.iter() {
+            let selector = Selector::Literal(self.scoped_selector(&rule.selector));
+            rule.block.flatten(&selector, &scope, &mut chunks);
+        }
+
+        // the struct is spanned to the user's code, otherwise the compiler will treat it as
+        // coming from an external macro and will not report the unused fields
+        tokens.extend(quote_spanned! {name.span()=>
+            #(#attrs)*
+            #[derive(Debug, Clone, Copy)]
+            #vis struct #name {
+                #(
+                    #[doc = #docs]
+                    #vis #fields: &'static str,
+                )*
+            }
+        });
+
+        tokens.extend(quote! {
+            #[allow(dead_code)]
+            impl #name {
+                /// the hashed class names used in this stylesheet
+                #vis const fn classes() -> Self {
+                    Self {
+                        #(#fields: #hashed,)*
+                    }
+                }
+
+                /// the css of this stylesheet, with the class names replaced with their
+                /// scoped names
+                #vis fn stylesheet() -> String {
+                    <[String]>::join(&[#(#chunks,)*], "\n")
+                }
+            }
+        });
+    }
+}
+
+/// extract the class names used in the selector, ie: `.button:hover .label` has `button` and
+/// `label`
+fn class_names(selector: &str) -> Vec<String> {
+    let mut classes = vec![];
+    replace_class_names(selector, |class| {
+        classes.push(class.to_string());
+        class.to_string()
+    });
+    classes
+}
+
+/// the field name of the class in the generated struct, `btn-primary` becomes `btn_primary`
+fn field_name(class: &str) -> String {
+    class.replace('-', "_")
+}
+
+/// the identifier of the field, class names which are rust keywords such as `type` are raw
+/// identifiers, ie: `r#type`
+fn field_ident(field: &str, span: Span) -> Result<Ident> {
+    if syn::parse_str::<Ident>(field).is_ok() {
+        return Ok(Ident::new(field, span));
+    }
+    match field {
+        // these keywords can not be raw identifiers
+        "_" | "crate" | "self" | "Self" | "super" => Err(syn::Error::new(
+            span,
+            format!("the class `.{field}` can not be used as the name of a field"),
+        )),
+        _ => Ok(Ident::new_raw(field, span)),
+    }
+}
+
+/// replace each class names in the selector with the result of `f`.
+/// The attribute selectors and quoted strings are kept as is, ie: the `.pdf` in `[href$=".pdf"]`
+fn replace_class_names(selector: &str, mut f: impl FnMut(&str) -> String) -> String {
+    let mut out = String::with_capacity(selector.len());
+    let mut chars = selector.char_indices().peekable();
+    // the closing character of the attribute selector or quoted string we are in
+    let mut closing: Vec<char> = vec![];
+    while let Some((i, ch)) = chars.next() {
+        out.push(ch);
+        match closing.last() {
+            Some('"' | '\'') if ch == '\\' => {
+                // the escaped character is part of the string
+                if let Some((_, escaped)) = chars.next() {
+                    out.push(escaped);
+                }
+                continue;
+            }
+            Some(quote @ ('"' | '\'')) => {
+                if ch == *quote {
+                    closing.pop();
+                }
+                continue;
+            }
+            _ => (),
+        }
+        match ch {
+            '[' => closing.push(']'),
+            '"' | '\'' => closing.push(ch),
+            ']' if closing.last() == Some(&']') => {
+                closing.pop();
+            }
+            _ => (),
+        }
+        let starts_class = closing.is_empty()
+            && ch == '.'
+            && chars
+                .peek()
+                .map(|(_, c)| c.is_ascii_alphabetic() || *c == '_')
+                .unwrap_or(false);
+        if starts_class {
+            let start = i + 1;
+            let mut end = start;
+            while let Some((j, c)) = chars.peek() {
+                if c.is_ascii_alphanumeric() || *c == '_' || *c == '-' {
+                    end = j + c.len_utf8();
+                    chars.next();
+                } else {
+                    break;
+                }
+            }
+            out.push_str(&f(&selector[start..end]));
+        }
+    }
+    out
+}
+
+/// a stable 32-bit FNV-1a hash, so the class names don't change across compiler versions
+fn fnv1a<'a>(parts: impl IntoIterator<Item = &'a str>) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for part in parts {
+        for byte in part.bytes().chain(std::iter::once(0)) {
+            hash ^= u32::from(byte);
+            hash = hash.wrapping_mul(0x0100_0193);
+        }
+    }
+    hash
+}
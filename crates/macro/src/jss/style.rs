@@ -1,12 +1,14 @@
-use quote::{quote, ToTokens};
-use sauron_core::html::lookup::match_property;
+use quote::{quote, quote_spanned, ToTokens};
+use sauron_core::html::lookup::{match_property, property_value_kind, ValueKind};
 use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
 use syn::{Expr, Ident, Lit, Result, Token};
 
 /// ```ignore
 /// background_color: "red",
 /// border: (px(1), "solid", "green"),
 /// ```
+#[derive(Default)]
 pub(crate) struct Style {
     properties: Vec<(Option<Annotation>, Property)>,
 }
@@ -26,32 +28,48 @@ struct Property {
 
 /// `border`
 /// `"background-color"`
-struct PropertyName(String);
+/// the kind of value expected by well-known properties written as ident,
+/// quoted properties are not checked
+struct PropertyName(String, Option<ValueKind>);
 
 impl Parse for Style {
     /// ```ignore
     /// $(<property> : <pair>,)*
     /// ```
     fn parse(input: ParseStream) -> Result<Self> {
-        let mut properties = vec![];
+        let mut style = Style::default();
         while !input.is_empty() {
-            // must be attribute annotations
-            let anotation = if input.peek(Token![#]) {
-                Some(input.parse()?)
-            } else {
-                None
-            };
-            let kv = input.parse()?;
-            if input.peek(Token![,]) && !input.peek2(syn::token::Brace) {
-                input.parse::<Token![,]>()?;
-            }
-            properties.push((anotation, kv));
+            style.parse_property(input)?;
         }
-        Ok(Self { properties })
+        Ok(style)
     }
 }
 
 impl Style {
+    /// parse a single property along with its annotation and the trailing comma
+    /// ```ignore
+    /// $(#[<annotation>])? <property> : <pair> (,)?
+    /// ```
+    pub(crate) fn parse_property(&mut self, input: ParseStream) -> Result<()> {
+        // must be attribute annotations
+        let anotation = if input.peek(Token![#]) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        let kv = input.parse()?;
+        if input.peek(Token![,]) && !input.peek2(syn::token::Brace) {
+            input.parse::<Token![,]>()?;
+        }
+        self.properties.push((anotation, kv));
+        Ok(())
+    }
+
+    /// returns true if there is no property in this style
+    pub(crate) fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+
     pub(crate) fn to_attr_tokens(&self) -> proc_macro2::TokenStream {
         let style_tokens = self.to_style_array_tokens();
         quote! {
@@ -125,11 +143,27 @@ impl Property {
     fn to_tokens_with_pretty(&self) -> proc_macro2::TokenStream {
         let property = &self.property;
         let value = &self.value;
+        // the value is spanned, so a mismatched value is reported in the user's code
+        let value = match property.1 {
+            Some(ValueKind::Length) => {
+                quote_spanned! {value.span()=> sauron::html::units::expect_length(#value)}
+            }
+            Some(ValueKind::Color) => {
+                quote_spanned! {value.span()=> sauron::html::units::expect_color(#value)}
+            }
+            Some(ValueKind::Angle) => {
+                quote_spanned! {value.span()=> sauron::html::units::expect_angle(#value)}
+            }
+            Some(ValueKind::Time) => {
+                quote_spanned! {value.span()=> sauron::html::units::expect_time(#value)}
+            }
+            None => quote! { sauron::vdom::Value::from(#value) },
+        };
 
         quote! {
             sauron::vdom::Style{
                 name: std::borrow::Cow::from(#property),
-                value: sauron::vdom::Value::from(#value),
+                value: #value,
             }
         }
     }
@@ -143,7 +177,10 @@ impl Parse for PropertyName {
         if let Ok(ident) = input.parse::<Ident>() {
             let property_name = ident.to_string();
             match match_property(&property_name) {
-                Some(matched) => Ok(PropertyName(matched.to_string())),
+                Some(matched) => Ok(PropertyName(
+                    matched.to_string(),
+                    property_value_kind(matched),
+                )),
                 None => Err(syn::Error::new(
                     input.span(),
                     format!(
@@ -153,7 +190,7 @@ impl Parse for PropertyName {
                 )),
             }
         } else if let Ok(Lit::Str(v)) = input.parse::<Lit>() {
-            Ok(PropertyName(v.value()))
+            Ok(PropertyName(v.value(), None))
         } else {
             Err(syn::Error::new(
                 input.span(),
@@ -14,6 +14,9 @@
 use quote::ToTokens;
 
 mod extract_skip_diff;
+mod extract_template;
+#[cfg(feature = "html-parser")]
+mod html_template;
 mod jss;
 mod node;
 mod view;
@@ -239,6 +242,67 @@ pub fn extract_skip_diff(input: proc_macro::TokenStream) -> proc_macro::TokenStr
 ///     \n";
 /// assert_eq!(expected, css);
 /// ```
+///
+/// Rules can be nested, where `&` refers to the parent selector, and a nested selector without
+/// `&` is a descendant of the parent. `@media`, `@supports`, `@container` and `@layer` can be
+/// nested in a selector, while `@keyframes` and `@font-face` are placed at the top level.
+/// The nested rules are flatten into plain css.
+/// ```rust
+/// use sauron::jss;
+///
+/// let css = jss!(
+///     ".button": {
+///         color: "red",
+///         "&:hover": {
+///             color: "blue",
+///         },
+///         "& > .icon": {
+///             width: "16px",
+///         },
+///         "@media (max-width: 800px)": {
+///             width: "100%",
+///         },
+///     },
+///
+///     "@keyframes spin": {
+///         from: { transform: "rotate(0deg)" },
+///         "100%": { transform: "rotate(360deg)" },
+///     },
+/// );
+///
+/// let expected = "\
+///     .button {\
+///     \n  color: red;\
+///     \n}\
+///     \n\
+///     \n.button:hover {\
+///     \n  color: blue;\
+///     \n}\
+///     \n\
+///     \n.button > .icon {\
+///     \n  width: 16px;\
+///     \n}\
+///     \n\
+///     \n@media (max-width: 800px) {\
+///     \n.button {\
+///     \n  width: 100%;\
+///     \n}\
+///     \n\
+///     \n}\
+///     \n\
+///     \n@keyframes spin {\
+///     \nfrom {\
+///     \n  transform: rotate(0deg);\
+///     \n}\
+///     \n\
+///     \n100% {\
+///     \n  transform: rotate(360deg);\
+///     \n}\
+///     \n\
+///     \n}\
+///     \n";
+/// assert_eq!(expected, css);
+/// ```
 #[proc_macro]
 pub fn jss(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let style_sheet = syn::parse_macro_input!(input as jss::StyleSheet);
@@ -275,6 +339,133 @@ pub fn jss_with_media(input: proc_macro::TokenStream) -> proc_macro::TokenStream
     css_media.to_token_stream().into()
 }
 
+/// Compile an html template into a view at build time.
+///
+/// The html is parsed at compile time and the equivalent [Node] construction code is emitted,
+/// malformed markup such as unclosed tags or invalid tags are compile errors.
+/// Placeholders are used to bind rust expressions:
+/// - `{{ expr }}` in text and in attribute values is interpolated as text
+/// - `{{{ expr }}}` in text inserts a child [Node]
+/// - `on_click="{{ expr }}"` or `on:click="{{ expr }}"` attaches an event listener
+///
+/// [Node]: https://docs.rs/sauron/0/sauron/type.Node.html
+///
+/// # Example:
+/// ```rust
+/// use sauron::{html::{attributes::class, events::on_click, *}, html_template, Node};
+///
+/// enum Msg {
+///     Click,
+/// }
+///
+/// let name = "World";
+/// let view: Node<Msg> = html_template!(r#"
+///     <div class="greeting {{ name.to_lowercase() }}">
+///         <button on_click="{{ |_| Msg::Click }}">Hello {{ name }}!</button>
+///         {{{ text("a child node") }}}
+///     </div>
+/// "#);
+///
+/// let expected: Node<Msg> = div(
+///     [class("greeting world")],
+///     [
+///         button([on_click(|_| Msg::Click)], [text("Hello World!")]),
+///         text("a child node"),
+///     ],
+/// );
+/// assert_eq!(view.render_to_string(), expected.render_to_string());
+/// ```
+#[cfg(feature = "html-parser")]
+#[proc_macro]
+pub fn html_template(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    html_template::to_token_stream(input).into()
+}
+
+/// Compile an html template file into a view at build time.
+/// The path is relative to the crate root, the same placeholders in [`html_template!`]
+/// can be used in the file.
+///
+/// ```rust,ignore
+/// let view: Node<Msg> = include_html!("templates/card.html");
+/// ```
+#[cfg(feature = "html-parser")]
+#[proc_macro]
+pub fn include_html(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    html_template::include_to_token_stream(input).into()
+}
+
+/// build a stylesheet whose class names are scoped to a generated struct
+///
+/// The class names are hashed at compile time using the crate name, the struct name and the
+/// selectors, so two components named `Button` in different crates will not collide.
+/// The view then refers to the class names through the fields of the struct
+/// instead of using strings.
+///
+/// A selector can be an identifier, which is a shorthand for a single class, or a string
+/// where every `.class` in it is replaced with its scoped name.
+/// The field of a class which is a rust keyword is a raw identifier, ie: `.type` is `r#type`.
+///
+/// # Example:
+/// ```rust
+/// use sauron::{jss_scoped, html::attributes::class, Attribute};
+///
+/// jss_scoped! {
+///     struct ButtonStyles;
+///
+///     button: {
+///         background_color: "red",
+///     },
+///
+///     ".button:hover .label": {
+///         color: "white",
+///     },
+/// }
+///
+/// let styles = ButtonStyles::classes();
+/// assert!(styles.button.starts_with("ButtonStyles_button_"));
+/// assert!(styles.label.starts_with("ButtonStyles_label_"));
+///
+/// let _: Attribute<()> = class(styles.label);
+///
+/// let expected = format!(
+///     ".{} {{\n  background-color: red;\n}}\n\n.{}:hover .{} {{\n  color: white;\n}}\n",
+///     styles.button, styles.button, styles.label
+/// );
+/// assert_eq!(expected, ButtonStyles::stylesheet());
+/// ```
+///
+/// Referring to a class that is not defined in the stylesheet is a compile error:
+/// ```rust,compile_fail
+/// use sauron::jss_scoped;
+///
+/// jss_scoped! {
+///     struct ButtonStyles;
+///     button: { color: "red" },
+/// }
+///
+/// let _ = ButtonStyles::classes().label;
+/// ```
+///
+/// Two classes which have the same field name such as `.btn-primary` and `.btn_primary`
+/// are a compile error too:
+/// ```rust,compile_fail
+/// use sauron::jss_scoped;
+///
+/// jss_scoped! {
+///     struct ButtonStyles;
+///     ".btn-primary, .btn_primary": { color: "red" },
+/// }
+/// ```
+///
+/// A class which is defined but never used in the view is not a compile error,
+/// since the macro can not see the views which use the struct.
+/// Like any other field, it is only reported as a `dead_code` warning when the struct is not `pub`.
+#[proc_macro]
+pub fn jss_scoped(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let scoped = syn::parse_macro_input!(input as jss::ScopedStyleSheet);
+    scoped.to_token_stream().into()
+}
+
 /// build a style attribute
 ///
 /// # Example:
@@ -291,6 +482,23 @@ pub fn jss_with_media(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 /// let expected: Attribute<()> = attr("style","background-color:red;border:1px solid green;width:100%;");
 /// assert_eq!(expected.render_to_string(), s1.render_to_string());
 /// ```
+///
+/// The values of well-known properties are checked against the kind of value they expect,
+/// such as a length for `width` and a color for `color`, while raw strings are accepted as is.
+/// ```rust,compile_fail
+/// use sauron::style;
+/// use sauron::html::units::{px, rgb};
+/// use sauron::html::attributes::Attribute;
+///
+/// let s1: Attribute<()> = style! {
+///     margin: rgb(255, 0, 0),
+/// };
+/// ```
+/// ```rust,compile_fail
+/// use sauron::html::units::px;
+///
+/// let width = px("abc");
+/// ```
 #[proc_macro]
 pub fn style(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let style = syn::parse_macro_input!(input as jss::Style);
@@ -3,15 +3,25 @@
 //!
 use proc_macro2::TokenStream;
 use quote::quote;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
 
 pub fn to_token_stream(input: proc_macro::TokenStream) -> TokenStream {
     let view = crate::node::to_token_stream(input.clone());
     let skip_diff = crate::extract_skip_diff::to_token_stream(input.clone());
+    let template = crate::extract_template::to_token_stream(input);
+    // the call site is the same for every expansion of a `view!` written in a `macro_rules!`,
+    // the hash of the skeleton tells apart the templates registered at the same call site
+    let mut hasher = DefaultHasher::new();
+    template.to_string().hash(&mut hasher);
+    let template_hash = format!("{:016x}", hasher.finish());
     quote! {
         fn view(&self) -> Node<Self::MSG> {
              ::sauron::Node::Leaf(sauron::vdom::Leaf::TemplatedView(sauron::vdom::TemplatedView{
                         view: Box::new(#view),
+                        template: std::rc::Rc::new(||#template),
                         skip_diff: std::rc::Rc::new(||#skip_diff),
+                        call_site: concat!(file!(), ":", line!(), ":", column!(), ":", #template_hash),
                     }))
         }
     }
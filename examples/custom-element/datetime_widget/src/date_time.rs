@@ -156,7 +156,7 @@ where
     }
 
     fn observed_attributes() -> Vec<AttributeName> {
-        vec!["date", "time", "interval"]
+        vec!["date".into(), "time".into(), "interval".into()]
     }
 
 
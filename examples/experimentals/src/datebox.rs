@@ -114,7 +114,7 @@ impl sauron::Component for DateBox {
 impl StatefulComponent for DateBox {
     /// this is called when the attributes in the mount is changed
     fn attribute_changed(&mut self, attr: DomAttr) {
-        match attr.name {
+        match attr.name.as_ref() {
             "time" => {
                 if let Some(new_value) = attr.value[0].as_string() {
                     Component::update(self, Msg::TimeChange(new_value));
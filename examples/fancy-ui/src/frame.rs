@@ -5,7 +5,7 @@ use sauron::dom::DomNode;
 use sauron::{
     dom::DomAttr,
     html::{attributes::*, events::*, *},
-    vdom::Callback,
+    vdom::{AttributeName, Callback},
     *,
 };
 use std::collections::BTreeMap;
@@ -343,8 +343,13 @@ where
     }
 
     /// what attributes this component is interested in
-    fn observed_attributes() -> Vec<&'static str> {
-        vec!["theme-primary", "theme-background", "feature", "status"]
+    fn observed_attributes() -> Vec<AttributeName> {
+        vec![
+            "theme-primary".into(),
+            "theme-background".into(),
+            "feature".into(),
+            "status".into(),
+        ]
     }
 }
 
@@ -616,7 +621,7 @@ impl<XMSG> StatefulComponent for Frame<XMSG> {
     /// called when any of the attributes in observed_attributes is changed
     fn attribute_changed(&mut self, attr: DomAttr) {
         log::info!("attribuite changed: {attr:?}");
-        match attr.name {
+        match attr.name.as_ref() {
             "theme-primary" => {
                 if let Some(primary) = attr.value[0].as_string() {
                     let background = &self.theme.background_color;
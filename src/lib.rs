@@ -42,8 +42,11 @@ pub mod prelude {
     pub use sauron_macro::node;
 
     #[cfg(feature = "with-jss")]
-    pub use sauron_macro::{jss, jss_with_media, style};
+    pub use sauron_macro::{jss, jss_scoped, jss_with_media, style};
 
     #[cfg(feature = "html-parser")]
-    pub use sauron_html_parser::{parse_html, raw_html};
+    pub use sauron_html_parser::{parse_html, raw_html, try_raw_html};
+
+    #[cfg(all(feature = "html-parser", feature = "sauron-macro"))]
+    pub use sauron_macro::{html_template, include_html};
 }
@@ -0,0 +1,140 @@
+use futures::channel::mpsc;
+use futures::StreamExt;
+use sauron::dom::Executor;
+use sauron::*;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Msg {
+    Fetch(u32),
+    Fetched(u32),
+    Listen,
+    Received(&'static str),
+}
+
+#[derive(Default)]
+struct App {
+    fetched: Vec<u32>,
+    received: Vec<&'static str>,
+    receiver: Option<mpsc::UnboundedReceiver<&'static str>>,
+}
+
+impl Application for App {
+    type MSG = Msg;
+
+    fn init(&mut self) -> Cmd<Msg> {
+        Cmd::new(async { Msg::Fetch(1) })
+    }
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Fetch(id) => Cmd::new(async move { Msg::Fetched(id) }),
+            Msg::Fetched(id) => {
+                self.fetched.push(id);
+                Cmd::none()
+            }
+            Msg::Listen => match self.receiver.take() {
+                Some(receiver) => Cmd::from_stream(receiver.map(Msg::Received)),
+                None => Cmd::none(),
+            },
+            Msg::Received(text) => {
+                self.received.push(text);
+                Cmd::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Node<Msg> {
+        ul([], self.fetched.iter().map(|id| li([], [text(id)])))
+    }
+}
+
+#[test]
+fn init_cmd_is_executed() {
+    let mut executor = Executor::new(App::default());
+    executor.init();
+    assert_eq!(executor.log(), [Msg::Fetch(1), Msg::Fetched(1)]);
+    assert_eq!(executor.view().render_to_string(), "<ul><li>1</li></ul>");
+    assert!(executor.is_settled());
+}
+
+#[test]
+fn msgs_of_cmds_are_fed_back() {
+    let mut executor = Executor::new(App::default());
+    executor.dispatch_multiple([Msg::Fetch(2), Msg::Fetch(3)]);
+    executor.settle();
+    assert_eq!(executor.app().fetched, [2, 3]);
+    assert_eq!(
+        executor.log(),
+        [
+            Msg::Fetch(2),
+            Msg::Fetch(3),
+            Msg::Fetched(2),
+            Msg::Fetched(3)
+        ]
+    );
+}
+
+#[test]
+fn stream_is_kept_running_until_it_ends() {
+    let (tx, rx) = mpsc::unbounded();
+    let mut executor = Executor::new(App {
+        receiver: Some(rx),
+        ..Default::default()
+    });
+    executor.dispatch(Msg::Listen);
+    executor.settle();
+    assert_eq!(executor.pending_count(), 1);
+
+    tx.unbounded_send("hello").unwrap();
+    tx.unbounded_send("world").unwrap();
+    executor.settle();
+    assert_eq!(executor.app().received, ["hello", "world"]);
+
+    drop(tx);
+    executor.run_until_done();
+    assert!(executor.is_settled());
+}
+
+#[test]
+fn sequence_and_then() {
+    let mut executor = Executor::new(App::default());
+    executor.spawn(Cmd::sequence([
+        Cmd::new(async { Msg::Fetched(1) }),
+        Cmd::new(async { Msg::Fetched(2) }).and_then(|msg| async move {
+            let Msg::Fetched(id) = msg else {
+                unreachable!()
+            };
+            Msg::Fetched(id * 10)
+        }),
+        Cmd::new(async { Msg::Fetched(3) }),
+    ]));
+    executor.run_until_done();
+    assert_eq!(executor.app().fetched, [1, 20, 3]);
+}
+
+#[test]
+fn race() {
+    let mut executor = Executor::new(App::default());
+    executor.spawn(Cmd::race([
+        Cmd::new(futures::future::pending()),
+        Cmd::new(async { Msg::Fetched(3) }),
+    ]));
+    executor.run_until_done();
+    assert_eq!(executor.app().fetched, [3]);
+}
+
+#[test]
+fn cancelled_cmd_is_dropped() {
+    let (tx, rx) = mpsc::unbounded();
+    let mut executor = Executor::new(App::default());
+    let (cmd, handle) = Cmd::from_stream(rx.map(Msg::Received)).cancellable();
+    executor.spawn(cmd);
+    tx.unbounded_send("before").unwrap();
+    executor.settle();
+
+    handle.cancel();
+    let _ = tx.unbounded_send("after");
+    executor.run_until_done();
+    assert_eq!(executor.app().received, ["before"]);
+    assert!(executor.is_settled());
+}
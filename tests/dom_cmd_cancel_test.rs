@@ -0,0 +1,91 @@
+#![deny(warnings)]
+use sauron::dom::delay;
+use sauron::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+enum Msg {
+    StartTicking,
+    Tick,
+    StartLoading,
+    Loaded,
+    Cancel,
+}
+
+#[derive(Default)]
+struct App {
+    ticks: u32,
+    loaded: bool,
+    handle: Option<CmdHandle>,
+}
+
+impl Application for App {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::StartTicking => {
+                let (cmd, handle) = Time::every(20, || Msg::Tick).cancellable();
+                self.handle = Some(handle);
+                cmd
+            }
+            Msg::Tick => {
+                self.ticks += 1;
+                Cmd::none()
+            }
+            Msg::StartLoading => {
+                let (cmd, handle) = Cmd::new(async {
+                    delay(100).await;
+                    Msg::Loaded
+                })
+                .cancellable();
+                self.handle = Some(handle);
+                cmd
+            }
+            Msg::Loaded => {
+                self.loaded = true;
+                Cmd::none()
+            }
+            Msg::Cancel => {
+                if let Some(handle) = self.handle.take() {
+                    handle.cancel();
+                    assert!(handle.is_cancelled());
+                }
+                Cmd::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div([], [text(self.ticks)])
+    }
+}
+
+#[wasm_bindgen_test]
+async fn cancelled_interval_stops_emitting() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(App::default());
+    program.dispatch(Msg::StartTicking);
+    delay(150).await;
+    assert!(program.app().ticks > 0);
+
+    program.dispatch(Msg::Cancel);
+    delay(50).await;
+    let ticks = program.app().ticks;
+    delay(150).await;
+    assert_eq!(program.app().ticks, ticks);
+}
+
+#[wasm_bindgen_test]
+async fn cancelled_future_is_dropped() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(App::default());
+    program.dispatch(Msg::StartLoading);
+    delay(20).await;
+    program.dispatch(Msg::Cancel);
+    delay(200).await;
+    assert!(!program.app().loaded);
+}
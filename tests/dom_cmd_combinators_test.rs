@@ -0,0 +1,101 @@
+#![deny(warnings)]
+use sauron::dom::delay;
+use sauron::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+enum Msg {
+    Run(fn() -> Cmd<Msg>),
+    Log(String),
+}
+
+#[derive(Default)]
+struct App {
+    log: Vec<String>,
+}
+
+impl Application for App {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Run(cmd) => cmd(),
+            Msg::Log(entry) => {
+                self.log.push(entry);
+                Cmd::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div([], [text(self.log.len())])
+    }
+}
+
+fn after(ms: i32, entry: &'static str) -> Cmd<Msg> {
+    Cmd::new(async move {
+        delay(ms).await;
+        Msg::Log(entry.to_string())
+    })
+}
+
+async fn run(cmd: fn() -> Cmd<Msg>) -> Vec<String> {
+    let mut program = Program::mount_to_body(App::default());
+    program.dispatch(Msg::Run(cmd));
+    delay(300).await;
+    let log = program.app().log.clone();
+    log
+}
+
+#[wasm_bindgen_test]
+async fn and_then_runs_the_follow_up() {
+    console_error_panic_hook::set_once();
+    let log = run(|| {
+        after(10, "user").and_then(|msg| async move {
+            let Msg::Log(user) = msg else { unreachable!() };
+            Msg::Log(format!("posts of {user}"))
+        })
+    })
+    .await;
+    assert_eq!(log, ["posts of user"]);
+}
+
+#[wasm_bindgen_test]
+async fn sequence_keeps_the_order() {
+    console_error_panic_hook::set_once();
+    let log =
+        run(|| Cmd::sequence([after(50, "first"), after(0, "second"), after(10, "third")])).await;
+    assert_eq!(log, ["first", "second", "third"]);
+}
+
+#[wasm_bindgen_test]
+async fn race_uses_the_first_msg() {
+    console_error_panic_hook::set_once();
+    let log = run(|| Cmd::race([after(100, "slow"), after(10, "fast")])).await;
+    assert_eq!(log, ["fast"]);
+}
+
+#[wasm_bindgen_test]
+async fn timeout_emits_the_fallback() {
+    console_error_panic_hook::set_once();
+    let log = run(|| {
+        Cmd::batch([
+            after(200, "slow").timeout(50, || Msg::Log("timed out".to_string())),
+            after(10, "fast").timeout(50, || Msg::Log("timed out".to_string())),
+        ])
+    })
+    .await;
+    assert_eq!(log, ["fast", "timed out"]);
+}
+
+#[wasm_bindgen_test]
+async fn stream_msgs_are_emitted() {
+    console_error_panic_hook::set_once();
+    let log = run(|| {
+        Cmd::from_stream(futures::stream::iter(["a", "b", "c"]))
+            .map_msg(|entry| Msg::Log(entry.to_string()))
+    })
+    .await;
+    assert_eq!(log, ["a", "b", "c"]);
+}
@@ -0,0 +1,89 @@
+#![deny(warnings)]
+use sauron::dom::{delay, Intercept, Middleware};
+use sauron::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Msg {
+    Increment,
+    Delete,
+    Later,
+}
+
+#[derive(Default)]
+struct App {
+    log: Vec<Msg>,
+}
+
+impl Application for App {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        self.log.push(msg);
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div([], [text(self.log.len())])
+    }
+}
+
+/// discards the msgs which are not allowed and counts the cmds
+#[derive(Default)]
+struct Guard {
+    cmds: Rc<RefCell<usize>>,
+}
+
+impl Middleware<Msg> for Guard {
+    fn before_update(&mut self, msg: Msg) -> Intercept<Msg> {
+        match msg {
+            Msg::Delete => Intercept::Discard,
+            Msg::Later => Intercept::Defer(Cmd::new(async {
+                delay(50).await;
+                Msg::Later
+            })),
+            msg => Intercept::Continue(msg),
+        }
+    }
+
+    fn after_update(&mut self, cmd: Cmd<Msg>) -> Cmd<Msg> {
+        *self.cmds.borrow_mut() += 1;
+        cmd
+    }
+}
+
+#[wasm_bindgen_test]
+async fn middlewares_intercept_msgs() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(App::default());
+    let observed = Rc::new(RefCell::new(vec![]));
+    let cmds = Rc::new(RefCell::new(0));
+    {
+        let observed = Rc::clone(&observed);
+        program.add_middleware(move |msg: Msg| {
+            observed.borrow_mut().push(msg.clone());
+            Intercept::Continue(msg)
+        });
+    }
+    program.add_middleware(Guard {
+        cmds: Rc::clone(&cmds),
+    });
+
+    program.dispatch_multiple([Msg::Later, Msg::Increment, Msg::Delete]);
+    delay(20).await;
+    assert_eq!(program.app().log, [Msg::Increment]);
+
+    delay(100).await;
+    // the deferred msg is not intercepted again by the middlewares before the guard
+    assert_eq!(program.app().log, [Msg::Increment, Msg::Later]);
+    assert_eq!(
+        *observed.borrow(),
+        [Msg::Later, Msg::Increment, Msg::Delete]
+    );
+    assert_eq!(*cmds.borrow(), 2);
+}
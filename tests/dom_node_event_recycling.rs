@@ -50,7 +50,7 @@ fn elements_with_different_event_should_not_be_recycle() {
     assert_eq!(
         patches,
         vec![Patch::add_attributes(
-            &"input",
+            "input",
             TreePath::new(vec![]),
             vec![&cb2]
         )]
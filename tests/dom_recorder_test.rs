@@ -0,0 +1,114 @@
+#![deny(warnings)]
+use sauron::dom::{delay, Recorder};
+use sauron::*;
+use serde::{Deserialize, Serialize};
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Msg {
+    Add(i32),
+    Reset,
+}
+
+#[derive(Default, Clone)]
+struct Counter {
+    count: i32,
+}
+
+impl Application for Counter {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Add(n) => self.count += n,
+            Msg::Reset => self.count = 0,
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div([], [text(self.count)])
+    }
+}
+
+#[test]
+fn replay_loaded_msgs() {
+    let recorder = Recorder::new(10);
+    recorder
+        .load_json(
+            r#"[{"msg":{"Add":2},"snapshot":null},{"msg":{"Add":3}},{"msg":"Reset"},{"msg":{"Add":7}}]"#,
+            Counter::default(),
+        )
+        .expect("must load");
+    assert_eq!(recorder.len(), 4);
+    assert_eq!(recorder.position(), 4);
+    assert_eq!(recorder.replay(2).count, 5);
+    assert_eq!(recorder.replay(3).count, 0);
+    assert_eq!(recorder.replay(usize::MAX).count, 7);
+}
+
+#[test]
+fn export_to_json() {
+    let recorder = Recorder::new(10);
+    let json = r#"[{"msg":{"Add":2},"snapshot":"2"},{"msg":"Reset","snapshot":null}]"#;
+    recorder
+        .load_json(json, Counter::default())
+        .expect("must load");
+    assert_eq!(recorder.to_json().expect("must export"), json);
+    assert_eq!(
+        recorder.records()[0],
+        dom::Record {
+            msg: Msg::Add(2),
+            snapshot: Some("2".to_string())
+        }
+    );
+}
+
+#[wasm_bindgen_test]
+async fn step_through_the_recorded_msgs() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(Counter::default());
+    let recorder = Recorder::new(2).with_snapshot(|app: &Counter| app.count.to_string());
+    program.record(&recorder);
+
+    program.dispatch_multiple([Msg::Add(1), Msg::Add(2), Msg::Add(3)]);
+    delay(50).await;
+    // only the last 2 msgs are kept, the dropped msg is applied to the base state
+    assert_eq!(recorder.len(), 2);
+    assert_eq!(recorder.records()[1].snapshot.as_deref(), Some("6"));
+    assert_eq!(recorder.replay(0).count, 1);
+
+    recorder.step_backward(&mut program);
+    assert_eq!(program.app().count, 3);
+    recorder.step_forward(&mut program);
+    assert_eq!(program.app().count, 6);
+
+    recorder.travel_to(&mut program, 0);
+    assert_eq!(program.app().count, 1);
+    program.dispatch(Msg::Add(10));
+    delay(50).await;
+    // the records after the position are replaced
+    assert_eq!(recorder.len(), 1);
+    assert_eq!(program.app().count, 11);
+}
+
+#[wasm_bindgen_test]
+async fn replay_from_the_state_when_the_recording_started() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(Counter::default());
+    program.dispatch(Msg::Add(5));
+    delay(50).await;
+    let recorder = Recorder::new(10);
+    program.record(&recorder);
+
+    program.dispatch(Msg::Add(1));
+    delay(50).await;
+    assert_eq!(recorder.len(), 1);
+    assert_eq!(recorder.replay(0).count, 5);
+    recorder.step_backward(&mut program);
+    assert_eq!(program.app().count, 5);
+}
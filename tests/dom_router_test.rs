@@ -0,0 +1,129 @@
+#![deny(warnings)]
+use sauron::dom::delay;
+use sauron::dom::router::{current_route, navigate, on_route_change, redirect, Route};
+use sauron::*;
+use std::cell::Cell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[derive(Debug, Clone, PartialEq)]
+enum Page {
+    Home,
+    About,
+}
+
+impl Route for Page {
+    fn from_url(url: &str) -> Option<Self> {
+        match url {
+            "/" => Some(Page::Home),
+            "/about" => Some(Page::About),
+            _ => None,
+        }
+    }
+
+    fn to_url(&self) -> String {
+        match self {
+            Page::Home => "/".to_string(),
+            Page::About => "/about".to_string(),
+        }
+    }
+}
+
+enum Msg {
+    Navigate(Page),
+    Redirect(Page),
+    RouteChanged(Option<Page>),
+}
+
+#[derive(Default)]
+struct App {
+    routes: Vec<Option<Page>>,
+}
+
+impl Application for App {
+    type MSG = Msg;
+
+    fn init(&mut self) -> Cmd<Msg> {
+        on_route_change(Msg::RouteChanged)
+    }
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Navigate(page) => navigate(&page),
+            Msg::Redirect(page) => redirect(&page),
+            Msg::RouteChanged(route) => {
+                self.routes.push(route);
+                Cmd::none()
+            }
+        }
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div([], [text(self.routes.len())])
+    }
+}
+
+#[wasm_bindgen_test]
+async fn navigate_emits_the_route_without_a_popstate() {
+    console_error_panic_hook::set_once();
+
+    let popstates = Rc::new(Cell::new(0));
+    let counted = Rc::clone(&popstates);
+    let on_popstate: Closure<dyn FnMut(web_sys::Event)> =
+        Closure::new(move |_| counted.set(counted.get() + 1));
+    window()
+        .add_event_listener_with_callback("popstate", on_popstate.as_ref().unchecked_ref())
+        .expect("must add listener");
+
+    let mut program = Program::mount_to_body(App::default());
+    program.dispatch(Msg::Navigate(Page::About));
+    delay(50).await;
+    assert_eq!(current_route::<Page>(), Some(Page::About));
+    assert_eq!(program.app().routes, vec![Some(Page::About)]);
+
+    program.dispatch(Msg::Redirect(Page::Home));
+    delay(50).await;
+    assert_eq!(current_route::<Page>(), Some(Page::Home));
+    assert_eq!(
+        program.app().routes,
+        vec![Some(Page::About), Some(Page::Home)]
+    );
+    assert_eq!(popstates.get(), 0);
+
+    window()
+        .remove_event_listener_with_callback("popstate", on_popstate.as_ref().unchecked_ref())
+        .expect("must remove listener");
+}
+
+#[wasm_bindgen_test]
+async fn every_subscriber_is_notified_of_a_clicked_link() {
+    console_error_panic_hook::set_once();
+
+    let mut first = Program::mount_to_body(App::default());
+    let mut second = Program::mount_to_body(App::default());
+    first.dispatch(Msg::Redirect(Page::Home));
+    delay(50).await;
+
+    let link: web_sys::HtmlElement = document()
+        .create_element("a")
+        .expect("must create link")
+        .unchecked_into();
+    link.set_attribute("href", "/about").expect("must set href");
+    document()
+        .body()
+        .expect("must have a body")
+        .append_child(&link)
+        .expect("must append link");
+    link.click();
+    delay(50).await;
+
+    assert_eq!(current_route::<Page>(), Some(Page::About));
+    assert_eq!(first.app().routes.last(), Some(&Some(Page::About)));
+    assert_eq!(second.app().routes.last(), Some(&Some(Page::About)));
+
+    link.remove();
+    first.unmount();
+    second.unmount();
+}
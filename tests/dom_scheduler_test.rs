@@ -0,0 +1,103 @@
+#![deny(warnings)]
+use sauron::dom::{delay, MountProcedure, Scheduler, Task};
+use sauron::*;
+use std::cell::RefCell;
+use std::rc::Rc;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+enum Msg {
+    Add(i32),
+}
+
+#[derive(Default)]
+struct Counter {
+    count: i32,
+}
+
+impl Application for Counter {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        let Msg::Add(n) = msg;
+        self.count += n;
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div([class("counter")], [text(self.count)])
+    }
+}
+
+fn mount(scheduler: Scheduler) -> Program<Counter> {
+    let mount_node = document().create_element("div").expect("must create");
+    let body = document().body().expect("must have a body");
+    body.append_child(&mount_node).expect("must append");
+    let mut program = Program::new(Counter::default()).with_scheduler(scheduler);
+    program.mount(&mount_node, MountProcedure::append());
+    program
+}
+
+fn rendered(program: &Program<Counter>) -> String {
+    program
+        .root_node
+        .borrow()
+        .as_ref()
+        .expect("must be mounted")
+        .as_element()
+        .text_content()
+        .expect("must have a text")
+}
+
+#[wasm_bindgen_test]
+async fn manual_scheduler_applies_the_msgs_when_flushed() {
+    console_error_panic_hook::set_once();
+
+    let mut program = mount(Scheduler::manual());
+    program.dispatch_multiple([Msg::Add(1), Msg::Add(2)]);
+    delay(50).await;
+    assert_eq!(program.app().count, 0);
+
+    program.flush();
+    assert_eq!(program.app().count, 3);
+    assert_eq!(rendered(&program), "3");
+}
+
+#[wasm_bindgen_test]
+async fn custom_scheduler_batches_the_msgs_dispatched_before_it_runs() {
+    console_error_panic_hook::set_once();
+
+    let tasks: Rc<RefCell<Vec<Task>>> = Rc::new(RefCell::new(vec![]));
+    let scheduled = Rc::clone(&tasks);
+    let mut program = mount(Scheduler::custom(move |task| {
+        scheduled.borrow_mut().push(task)
+    }));
+    program.dispatch(Msg::Add(1));
+    program.dispatch(Msg::Add(2));
+    assert_eq!(tasks.borrow().len(), 1);
+
+    let task = tasks.borrow_mut().pop().expect("must be scheduled");
+    task();
+    assert_eq!(program.app().count, 3);
+    assert_eq!(rendered(&program), "3");
+}
+
+#[wasm_bindgen_test]
+async fn render_within_the_frame_budget_is_deferred() {
+    console_error_panic_hook::set_once();
+
+    let mut program = mount(Scheduler::immediate().with_frame_budget(200.0));
+    program.dispatch(Msg::Add(1));
+    delay(20).await;
+    assert_eq!(rendered(&program), "1");
+
+    program.dispatch(Msg::Add(1));
+    delay(20).await;
+    // the msg is applied, but the view is only rendered once the frame budget has elapsed
+    assert_eq!(program.app().count, 2);
+    assert_eq!(rendered(&program), "1");
+
+    delay(250).await;
+    assert_eq!(rendered(&program), "2");
+}
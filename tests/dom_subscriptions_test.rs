@@ -0,0 +1,64 @@
+#![deny(warnings)]
+use sauron::dom::delay;
+use sauron::*;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+enum Msg {
+    Ping,
+    Stop,
+}
+
+struct App {
+    listening: bool,
+    pings: u32,
+}
+
+impl Application for App {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        match msg {
+            Msg::Ping => self.pings += 1,
+            Msg::Stop => self.listening = false,
+        }
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div([], [text(self.pings)])
+    }
+
+    fn subscriptions(&self) -> Subscription<Msg> {
+        if self.listening {
+            Subscription::on_window("ping", |_| Msg::Ping)
+        } else {
+            Subscription::none()
+        }
+    }
+}
+
+fn ping() {
+    let event = web_sys::Event::new("ping").expect("must create event");
+    window().dispatch_event(&event).expect("must dispatch");
+}
+
+#[wasm_bindgen_test]
+async fn listener_is_removed_when_no_longer_subscribed() {
+    console_error_panic_hook::set_once();
+
+    let mut program = Program::mount_to_body(App {
+        listening: true,
+        pings: 0,
+    });
+    ping();
+    delay(100).await;
+    assert_eq!(program.app().pings, 1);
+
+    program.dispatch(Msg::Stop);
+    delay(100).await;
+    ping();
+    delay(100).await;
+    assert_eq!(program.app().pings, 1);
+}
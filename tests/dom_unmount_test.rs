@@ -0,0 +1,76 @@
+#![deny(warnings)]
+use sauron::dom::delay;
+use sauron::*;
+use std::mem::ManuallyDrop;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+enum Msg {
+    Ping,
+}
+
+#[derive(Default)]
+struct App {
+    pings: u32,
+}
+
+impl Application for App {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        let Msg::Ping = msg;
+        self.pings += 1;
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        div(
+            [id("unmounted-app"), on_click(|_| Msg::Ping)],
+            [text(self.pings)],
+        )
+    }
+
+    fn subscriptions(&self) -> Subscription<Msg> {
+        Subscription::on_window("ping", |_| Msg::Ping)
+    }
+
+    fn stylesheet() -> Vec<String> {
+        vec!["#unmounted-app { color: red; }".to_string()]
+    }
+}
+
+fn ping() {
+    let event = web_sys::Event::new("ping").expect("must create event");
+    window().dispatch_event(&event).expect("must dispatch");
+}
+
+fn style_count() -> u32 {
+    document()
+        .query_selector_all("head style")
+        .expect("must query")
+        .length()
+}
+
+#[wasm_bindgen_test]
+async fn unmount_removes_the_view_subscriptions_and_styles() {
+    console_error_panic_hook::set_once();
+
+    let styles_before = style_count();
+    let mut program = Program::mount_to_body(App::default());
+    assert!(document().get_element_by_id("unmounted-app").is_some());
+    assert_eq!(style_count(), styles_before + 1);
+
+    program.unmount();
+    assert!(program.is_unmounted());
+    assert!(document().get_element_by_id("unmounted-app").is_none());
+    assert_eq!(style_count(), styles_before);
+
+    // the subscription is no longer listened and the dispatched msgs are ignored
+    ping();
+    program.dispatch(Msg::Ping);
+    delay(100).await;
+    assert_eq!(program.app().pings, 0);
+
+    drop(ManuallyDrop::into_inner(program));
+}
@@ -0,0 +1,161 @@
+#![deny(warnings)]
+use sauron::dom::delay;
+use sauron::*;
+use std::mem::ManuallyDrop;
+use wasm_bindgen_test::*;
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+enum Msg {
+    Click,
+}
+
+/// the initial dom of this view is created by cloning its template
+#[derive(Default)]
+struct Templated {
+    clicks: u32,
+}
+
+impl Application for Templated {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        let Msg::Click = msg;
+        self.clicks += 1;
+        Cmd::none()
+    }
+
+    view! {
+        <div class="counter">
+            <span class="label">"clicks:"</span>
+            <button class=format!("clicks-{}", self.clicks) on_click=|_| Msg::Click>
+                {text(self.clicks)}
+            </button>
+        </div>
+    }
+}
+
+/// the same view as `Templated`, which is created the usual way
+#[derive(Default)]
+struct Created {
+    clicks: u32,
+}
+
+impl Application for Created {
+    type MSG = Msg;
+
+    fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+        let Msg::Click = msg;
+        self.clicks += 1;
+        Cmd::none()
+    }
+
+    fn view(&self) -> Node<Msg> {
+        node! {
+            <div class="counter">
+                <span class="label">"clicks:"</span>
+                <button class=format!("clicks-{}", self.clicks) on_click=|_| Msg::Click>
+                    {text(self.clicks)}
+                </button>
+            </div>
+        }
+    }
+}
+
+/// every expansion of this macro has the same `view!` call site
+macro_rules! macro_app {
+    ($name:ident, $($view:tt)*) => {
+        #[derive(Default)]
+        struct $name;
+
+        impl Application for $name {
+            type MSG = Msg;
+
+            fn update(&mut self, _msg: Msg) -> Cmd<Msg> {
+                Cmd::none()
+            }
+
+            view! { $($view)* }
+        }
+    };
+}
+
+macro_app!(First, <div class="first"><span>"first"</span></div>);
+macro_app!(Second, <div class="second"><b>"second"</b></div>);
+
+fn outer_html<APP: Application>(program: &Program<APP>) -> String {
+    program
+        .root_node
+        .borrow()
+        .as_ref()
+        .expect("must be mounted")
+        .as_element()
+        .outer_html()
+}
+
+fn click<APP: Application>(program: &Program<APP>) {
+    let button = program
+        .root_node
+        .borrow()
+        .as_ref()
+        .expect("must be mounted")
+        .as_element()
+        .query_selector("button")
+        .expect("must query")
+        .expect("must have a button");
+    let event = web_sys::MouseEvent::new("click").expect("must create event");
+    button.dispatch_event(&event).expect("must dispatch");
+}
+
+#[wasm_bindgen_test]
+async fn template_clone_is_the_same_as_the_created_view() {
+    console_error_panic_hook::set_once();
+
+    // the first mount registers the template, the second one clones the cached template
+    let mut registered = Program::mount_to_body(Templated::default());
+    let mut cloned = Program::mount_to_body(Templated::default());
+    let mut created = Program::mount_to_body(Created::default());
+    let expected = outer_html(&created);
+    assert_eq!(
+        expected,
+        r#"<div class="counter"><span class="label">clicks:</span><button class="clicks-0">0</button></div>"#
+    );
+    assert_eq!(outer_html(&registered), expected);
+    assert_eq!(outer_html(&cloned), expected);
+
+    // the event listeners of the cloned views are attached
+    click(&registered);
+    click(&cloned);
+    click(&created);
+    delay(50).await;
+    assert_eq!(registered.app().clicks, 1);
+    assert_eq!(cloned.app().clicks, 1);
+    assert_eq!(created.app().clicks, 1);
+    let expected = outer_html(&created);
+    assert!(expected.contains(r#"<button class="clicks-1">1</button>"#));
+    assert_eq!(outer_html(&registered), expected);
+    assert_eq!(outer_html(&cloned), expected);
+
+    registered.unmount();
+    cloned.unmount();
+    created.unmount();
+    drop(ManuallyDrop::into_inner(registered));
+    drop(ManuallyDrop::into_inner(cloned));
+    drop(ManuallyDrop::into_inner(created));
+}
+
+#[wasm_bindgen_test]
+fn views_from_the_same_macro_rules_have_their_own_template() {
+    console_error_panic_hook::set_once();
+
+    let first = Program::mount_to_body(First);
+    let second = Program::mount_to_body(Second);
+    assert_eq!(
+        outer_html(&first),
+        r#"<div class="first"><span>first</span></div>"#
+    );
+    assert_eq!(
+        outer_html(&second),
+        r#"<div class="second"><b>second</b></div>"#
+    );
+}
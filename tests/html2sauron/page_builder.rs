@@ -0,0 +1,33 @@
+article(
+    [class("card"), attr("data-id", "42")],
+    [
+        header(
+            [],
+            [
+                h1([], [text("Hello "), html::symbol("&amp;"), text(" welcome")]),
+                a([href("/about"), title("About")], [text("about")]),
+            ],
+        ),
+        form(
+            [],
+            [
+                label([html::attributes::r#for("name")], [text("Name")]),
+                input([id("name"), html::attributes::r#type("text"), placeholder("your name")], []),
+                button([html::attributes::r#type("submit")], [text("Send")]),
+            ],
+        ),
+        p(
+            [html::attributes::styles([("color", "red"), ("margin", "0 4px")])],
+            [text("A "), b([], [text("bold")]), text(" word."), br([], []), text("Next line.")],
+        ),
+        svg(
+            [view_box("0 0 10 10")],
+            [
+                linear_gradient([id("g")], []),
+                circle([cx("5"), cy("5"), r("4"), fill("url(#g)")], []),
+                svg::tags::a([href("/x")], [svg::tags::text([x("1"), y("9")], [text("t")])]),
+            ],
+        ),
+        ul([], [li([], [text("one")]), li([], [text("two")])]),
+    ],
+)
@@ -0,0 +1,29 @@
+node! {
+    <article class="card" data-id="42">
+        <header>
+            <h1>"Hello "{html::symbol("&amp;")}" welcome"</h1>
+            <a href="/about" title="About">"about"</a>
+        </header>
+        <form>
+            <label for="name">"Name"</label>
+            <input id="name" type="text" placeholder="your name"/>
+            <button type="submit">"Send"</button>
+        </form>
+        <p style="color:red;margin:0 4px;">
+            "A "
+            <b>"bold"</b>
+            " word."
+            <br/>
+            "Next line."
+        </p>
+        <svg viewBox="0 0 10 10">
+            <linearGradient id="g"></linearGradient>
+            <circle cx="5" cy="5" r="4" fill="url(#g)"></circle>
+            {svg::tags::a([href("/x")], [svg::tags::text([x("1"), y("9")], [text("t")])])}
+        </svg>
+        <ul>
+            <li>"one"</li>
+            <li>"two"</li>
+        </ul>
+    </article>
+}
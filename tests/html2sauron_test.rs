@@ -0,0 +1,147 @@
+use sauron::html::html_element;
+use sauron::svg::SVG_NAMESPACE;
+use sauron::*;
+use sauron_html_parser::codegen::{html_to_rust, node_to_rust, Syntax};
+use sauron_html_parser::parse_html;
+
+/// the html of the fixtures in `tests/html2sauron`, the generated code is committed along with
+/// it and compiled in this test. Regenerate them when the generated code changes with:
+/// `html2sauron tests/html2sauron/page.html > tests/html2sauron/page_builder.rs`
+/// `html2sauron --node tests/html2sauron/page.html > tests/html2sauron/page_node_macro.rs`
+const PAGE: &str = include_str!("html2sauron/page.html");
+
+fn page_builder() -> Node<()> {
+    include!("html2sauron/page_builder.rs")
+}
+
+fn page_node_macro() -> Node<()> {
+    include!("html2sauron/page_node_macro.rs")
+}
+
+#[test]
+fn builder_syntax() {
+    let node: Node<()> = div(
+        [class("container"), attr("data-id", "42")],
+        [
+            h1([], [text("Hello")]),
+            html::link([attr("href", "x.css")], []),
+        ],
+    );
+    let expected = r#"div(
+    [class("container"), attr("data-id", "42")],
+    [h1([], [text("Hello")]), html::link([href("x.css")], [])],
+)"#;
+    assert_eq!(node_to_rust(&node, Syntax::Builder), expected);
+}
+
+#[test]
+fn long_lines_are_wrapped() {
+    let node: Node<()> = ul(
+        [class("list")],
+        [
+            li([], [text("the first item of this list")]),
+            li([], [text("the second item of this list")]),
+            li([], [text("the third item of this list")]),
+        ],
+    );
+    let expected = r#"ul(
+    [class("list")],
+    [
+        li([], [text("the first item of this list")]),
+        li([], [text("the second item of this list")]),
+        li([], [text("the third item of this list")]),
+    ],
+)"#;
+    assert_eq!(node_to_rust(&node, Syntax::Builder), expected);
+}
+
+#[test]
+fn node_macro_syntax() {
+    let node: Node<()> = div(
+        [class("container"), attr("data-id", "42")],
+        [h1([], [text("Hello")]), input([r#type("text")], [])],
+    );
+    let expected = r#"node! {
+    <div class="container" data-id="42">
+        <h1>"Hello"</h1>
+        <input type="text"/>
+    </div>
+}"#;
+    assert_eq!(node_to_rust(&node, Syntax::NodeMacro), expected);
+}
+
+#[test]
+fn svg_elements() {
+    let svg_ns = Some(SVG_NAMESPACE);
+    let node: Node<()> = html_element(
+        svg_ns,
+        "svg",
+        [attr("viewBox", "0 0 10 10")],
+        [
+            html_element(svg_ns, "linearGradient", [attr("id", "g")], [], false),
+            html_element(svg_ns, "a", [], [text("t")], false),
+        ],
+        false,
+    );
+    assert_eq!(
+        node_to_rust(&node, Syntax::Builder),
+        r#"svg([view_box("0 0 10 10")], [linear_gradient([id("g")], []), svg::tags::a([], [text("t")])])"#
+    );
+    let expected = r#"node! {
+    <svg viewBox="0 0 10 10">
+        <linearGradient id="g"></linearGradient>
+        {svg::tags::a([], [text("t")])}
+    </svg>
+}"#;
+    assert_eq!(node_to_rust(&node, Syntax::NodeMacro), expected);
+}
+
+#[test]
+fn inline_styles() {
+    let node: Node<()> = div(
+        [html::attributes::styles([
+            ("color", "red"),
+            ("margin", "0 4px"),
+        ])],
+        [],
+    );
+    let expected =
+        r#"div([html::attributes::styles([("color", "red"), ("margin", "0 4px")])], [])"#;
+    assert_eq!(node_to_rust(&node, Syntax::Builder), expected);
+}
+
+#[test]
+fn only_the_inserted_separators_are_skipped() {
+    // the texts of the dropped unknown tag are separated by an inserted separator
+    assert_eq!(
+        html_to_rust("<p>a<foo>b</foo></p>", Syntax::Builder).expect("must parse"),
+        r#"p([], [text("a"), text("b")])"#
+    );
+    // while the comment in the html is kept
+    assert_eq!(
+        html_to_rust("<p>a<!--separator-->b</p>", Syntax::Builder).expect("must parse"),
+        r#"p([], [text("a"), comment("separator"), text("b")])"#
+    );
+}
+
+#[test]
+fn generated_code_matches_the_fixtures() {
+    assert_eq!(
+        html_to_rust(PAGE, Syntax::Builder).expect("must parse"),
+        include_str!("html2sauron/page_builder.rs").trim_end()
+    );
+    assert_eq!(
+        html_to_rust(PAGE, Syntax::NodeMacro).expect("must parse"),
+        include_str!("html2sauron/page_node_macro.rs").trim_end()
+    );
+}
+
+#[test]
+fn generated_code_compiles_into_the_parsed_view() {
+    let parsed: Node<()> = parse_html(PAGE).ok().flatten().expect("must parse");
+    assert_eq!(page_builder().render_to_string(), parsed.render_to_string());
+    assert_eq!(
+        page_node_macro().render_to_string(),
+        parsed.render_to_string()
+    );
+}
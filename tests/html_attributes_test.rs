@@ -142,7 +142,7 @@ fn classes_test() {
     let classes: &Attribute<()> = elm
         .attributes()
         .iter()
-        .find(|att| att.name() == &"class")
+        .find(|att| att.name() == "class")
         .unwrap();
 
     assert_eq!(
@@ -172,7 +172,7 @@ fn should_merge_classes_flag() {
     let classes: &Attribute<()> = elm
         .attributes()
         .iter()
-        .find(|att| att.name() == &"class")
+        .find(|att| att.name() == "class")
         .unwrap();
 
     assert_eq!(
@@ -9,7 +9,7 @@ fn class_with_bool_value() {
     assert_eq!(
         diff(&old, &new),
         vec![Patch::add_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![&class(true)]
         )],
@@ -39,7 +39,7 @@ fn parent_of_matching_keyed_are_ignored() {
     assert_eq!(
         diff(&old, &new),
         vec![Patch::add_attributes(
-            &"ul",
+            "ul",
             TreePath::new(vec![]),
             vec![&class("changed")]
         )],
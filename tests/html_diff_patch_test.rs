@@ -34,7 +34,7 @@ fn nodes_with_event_must_be_replaced() {
     assert_eq!(
         patch,
         vec![Patch::remove_attributes(
-            &"input",
+            "input",
             TreePath::new(vec![]),
             vec![&event1]
         )]
@@ -49,7 +49,7 @@ fn change_class_attribute() {
     assert_eq!(
         diff(&old, &new),
         vec![Patch::add_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![&Attribute::with_multiple_values(
                 None,
@@ -90,10 +90,10 @@ fn truncate_children() {
     assert_eq!(
         dbg!(diff(&old, &new)),
         vec![
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![3]),),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![4]),),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![5]),),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![6]),),
+            Patch::remove_node(Some("div"), TreePath::new(vec![3]),),
+            Patch::remove_node(Some("div"), TreePath::new(vec![4]),),
+            Patch::remove_node(Some("div"), TreePath::new(vec![5]),),
+            Patch::remove_node(Some("div"), TreePath::new(vec![6]),),
         ],
         "Should truncate children"
     );
@@ -129,13 +129,13 @@ fn truncate_children_different_attributes() {
     assert_eq!(
         patch,
         vec![
-            Patch::add_attributes(&"div", TreePath::new(vec![0]), vec![&class("class5")]),
-            Patch::add_attributes(&"div", TreePath::new(vec![1]), vec![&class("class6")]),
-            Patch::add_attributes(&"div", TreePath::new(vec![2]), vec![&class("class7")]),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![3]),),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![4]),),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![5]),),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![6]),),
+            Patch::add_attributes("div", TreePath::new(vec![0]), vec![&class("class5")]),
+            Patch::add_attributes("div", TreePath::new(vec![1]), vec![&class("class6")]),
+            Patch::add_attributes("div", TreePath::new(vec![2]), vec![&class("class7")]),
+            Patch::remove_node(Some("div"), TreePath::new(vec![3]),),
+            Patch::remove_node(Some("div"), TreePath::new(vec![4]),),
+            Patch::remove_node(Some("div"), TreePath::new(vec![5]),),
+            Patch::remove_node(Some("div"), TreePath::new(vec![6]),),
         ],
         "Should truncate children"
     );
@@ -152,11 +152,11 @@ fn replace_node2() {
         patch,
         vec![
             Patch::replace_node(
-                Some(&"b"),
+                Some("b"),
                 TreePath::new(vec![0]),
                 vec![&i(vec![], vec![text("1")])]
             ),
-            Patch::replace_node(Some(&"b"), TreePath::new(vec![1]), vec![&i(vec![], vec![])]),
+            Patch::replace_node(Some("b"), TreePath::new(vec![1]), vec![&i(vec![], vec![])]),
         ],
         "ReplaceNode node with a child",
     )
@@ -169,7 +169,7 @@ fn remove_nodes1() {
 
     assert_eq!(
         dbg!(diff(&old, &new)),
-        vec![Patch::clear_children(Some(&"div"), TreePath::new(vec![]),),],
+        vec![Patch::clear_children(Some("div"), TreePath::new(vec![]),),],
         "Remove all child nodes at and after child sibling index 1",
     );
 }
@@ -197,8 +197,8 @@ fn remove_nodes2() {
     assert_eq!(
         dbg!(diff(&old, &new)),
         vec![
-            Patch::remove_node(Some(&"i"), TreePath::new(vec![0, 1]),),
-            Patch::remove_node(Some(&"strong"), TreePath::new(vec![1]),),
+            Patch::remove_node(Some("i"), TreePath::new(vec![0, 1]),),
+            Patch::remove_node(Some("strong"), TreePath::new(vec![1]),),
         ],
         "Remove a child and a grandchild node",
     );
@@ -220,8 +220,8 @@ fn remove_nodes3() {
     assert_eq!(
         dbg!(diff(&old, &new)),
         vec![
-            Patch::remove_node(Some(&"i"), TreePath::new(vec![0, 1]),),
-            Patch::replace_node(Some(&"b"), TreePath::new(vec![1]), vec![&i(vec![], vec![])]),
+            Patch::remove_node(Some("i"), TreePath::new(vec![0, 1]),),
+            Patch::replace_node(Some("b"), TreePath::new(vec![1]), vec![&i(vec![], vec![])]),
         ],
         "Removing child and change next node after parent",
     )
@@ -234,7 +234,7 @@ fn add_attributes() {
     assert_eq!(
         diff(&old, &new),
         vec![Patch::add_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![&id("hello")]
         )],
@@ -247,7 +247,7 @@ fn add_attributes() {
     assert_eq!(
         diff(&old, &new),
         vec![Patch::add_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![&id("hello")]
         )],
@@ -262,7 +262,7 @@ fn add_style_attributes() {
     assert_eq!(
         diff(&old, &new),
         vec![Patch::add_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![&style!("display": "none")]
         )],
@@ -283,7 +283,7 @@ fn add_style_attributes_1_change() {
     assert_eq!(
         diff(&old, &new),
         vec![Patch::add_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![&Attribute::with_multiple_values(
                 None,
@@ -317,7 +317,7 @@ fn remove_style_attributes() {
     assert_eq!(
         diff(&old, &new),
         vec![Patch::remove_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![&style!("display": "block")]
         )],
@@ -333,7 +333,7 @@ fn remove_events_will_become_replace_node() {
     assert_eq!(
         diff(&old, &new),
         vec![Patch::remove_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![&event1]
         )],
@@ -377,7 +377,7 @@ fn text_changed_in_keyed_elements() {
                 TreePath::new(vec![0, 2, 0]),
                 vec![&text("item3 with changes")]
             ),
-            Patch::remove_node(Some(&"article"), TreePath::new(vec![0, 0]),),
+            Patch::remove_node(Some("article"), TreePath::new(vec![0, 0]),),
         ]
     );
 }
@@ -406,7 +406,7 @@ fn multiple_style_calls() {
     assert_eq!(
         patches,
         vec![Patch::add_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![
                 &style!("font-family": "monospace1"),
@@ -0,0 +1,108 @@
+use sauron::html::attributes::attr;
+use sauron::*;
+use sauron_html_parser::document::{parse_document, Document};
+
+#[test]
+fn parse_head_and_body() {
+    let html = r#"<!DOCTYPE html>
+<html lang="en">
+  <head>
+    <meta charset="utf-8">
+    <title>Home</title>
+    <meta name="description" content="the home page">
+  </head>
+  <body class="dark"><main>Hi</main></body>
+</html>"#;
+    let doc: Document<()> = parse_document(html).expect("must parse");
+    assert_eq!(doc.doctype.as_deref(), Some("html"));
+    assert_eq!(doc.html_attributes, vec![lang("en")]);
+    assert_eq!(doc.head.len(), 3);
+    assert_eq!(doc.title().as_deref(), Some("Home"));
+    assert_eq!(doc.meta("description").as_deref(), Some("the home page"));
+    assert_eq!(doc.body_attributes, vec![class("dark")]);
+    assert_eq!(doc.body, vec![main([], [text("Hi")])]);
+}
+
+#[test]
+fn elements_without_head_and_body() {
+    let doc: Document<()> = Document::from(node! {
+        <title>"Home"</title>
+        <link rel="icon" href="/favicon.ico"/>
+        <h1>"Hello"</h1>
+        <script src="/app.js"></script>
+    });
+    assert_eq!(doc.doctype, None);
+    assert_eq!(doc.title().as_deref(), Some("Home"));
+    assert_eq!(doc.head.len(), 2);
+    assert_eq!(doc.body.len(), 2);
+}
+
+#[test]
+fn text_before_the_body_is_placed_in_the_body() {
+    let doc: Document<()> = parse_document("Hello <b>x</b>").expect("must parse");
+    assert!(doc.head.is_empty());
+    assert_eq!(doc.body.first(), Some(&text("Hello ")));
+    assert_eq!(doc.body.last(), Some(&b([], [text("x")])));
+}
+
+#[test]
+fn doctype_is_only_set_when_the_html_has_one() {
+    let empty: Document<()> = parse_document("").expect("must parse");
+    let paragraph: Document<()> = parse_document("<p>").expect("must parse");
+    let page: Document<()> = parse_document("<!doctype html><p>").expect("must parse");
+    assert_eq!(empty.doctype, None);
+    assert_eq!(paragraph.doctype, None);
+    assert_eq!(page.doctype.as_deref(), Some("html"));
+}
+
+#[test]
+fn manipulate_and_render() {
+    let mut doc: Document<()> = Document::default();
+    doc.set_title("App");
+    doc.set_meta("viewport", "width=device-width");
+    doc.add_stylesheet("/style.css");
+    doc.add_inline_script("main()");
+    doc.set_body([div([id("app")], [])]);
+    doc.set_meta("viewport", "width=480");
+    doc.html_attributes.push(lang("en"));
+    assert_eq!(
+        doc.render_to_string(),
+        concat!(
+            r#"<!doctype html><html lang="en"><head><title>App</title>"#,
+            r#"<meta name="viewport" content="width=480"/>"#,
+            r#"<link rel="stylesheet" href="/style.css"/><script>main()</script></head>"#,
+            r#"<body><div id="app"></div></body></html>"#
+        )
+    );
+    doc.body_attributes.push(attr("data-theme", "dark"));
+    assert!(doc
+        .render_to_string()
+        .contains(r#"<body data-theme="dark">"#));
+}
+
+#[test]
+fn title_entities_are_decoded() {
+    let doc: Document<()> =
+        parse_document("<html><head><title>Tom &amp; Jerry &lt;3</title></head></html>")
+            .expect("must parse");
+    assert_eq!(doc.title().as_deref(), Some("Tom & Jerry <3"));
+}
+
+#[test]
+fn render_the_same_as_the_node_of_the_page() {
+    let html = r#"<!doctype html><html lang="en"><head><title>Home</title><meta charset="utf-8"/></head><body class="dark"><main>Hi</main>text</body></html>"#;
+    let doc: Document<()> = parse_document(html).expect("must parse");
+    assert_eq!(doc.render_to_string(), html);
+    assert_eq!(
+        doc.render_to_string(),
+        doc.clone().into_node().render_to_string()
+    );
+    let mut pretty = String::new();
+    doc.render(&mut pretty).expect("must render");
+    assert_eq!(pretty, doc.clone().into_node().render_to_string_pretty());
+
+    let empty: Document<()> = Document::default();
+    let mut pretty = String::new();
+    empty.render(&mut pretty).expect("must render");
+    assert_eq!(pretty, empty.clone().into_node().render_to_string_pretty());
+}
@@ -153,3 +153,271 @@ fn test_jss_ns_with_media_query() {
             \n";
     assert_eq!(expected, css);
 }
+
+#[test]
+fn test_jss_scoped() {
+    jss_scoped! {
+        struct CardStyles;
+
+        card: {
+            display: "flex",
+        },
+
+        ".card > .card-title": {
+            font_weight: "bold",
+        },
+    }
+
+    let styles = CardStyles::classes();
+    assert_ne!(styles.card, "card");
+    assert_ne!(styles.card, styles.card_title);
+
+    let expected = format!(
+        ".{card} {{\n  display: flex;\n}}\n\n.{card} > .{title} {{\n  font-weight: bold;\n}}\n",
+        card = styles.card,
+        title = styles.card_title,
+    );
+    assert_eq!(expected, CardStyles::stylesheet());
+}
+
+#[test]
+fn test_jss_scoped_same_name_in_different_structs() {
+    jss_scoped! {
+        struct PrimaryButton;
+        button: { color: "red" },
+    }
+
+    jss_scoped! {
+        struct SecondaryButton;
+        button: { color: "red" },
+    }
+
+    assert_ne!(
+        PrimaryButton::classes().button,
+        SecondaryButton::classes().button
+    );
+}
+
+#[test]
+fn test_jss_scoped_keyword_class_names() {
+    jss_scoped! {
+        struct InputStyles;
+        ".type > .box": { color: "red" },
+    }
+
+    let styles = InputStyles::classes();
+    assert!(styles.r#type.starts_with("InputStyles_type_"));
+    assert!(styles.r#box.starts_with("InputStyles_box_"));
+}
+
+#[test]
+fn test_jss_scoped_attribute_selectors_are_not_classes() {
+    jss_scoped! {
+        struct LinkStyles;
+        ".link[href$=\".pdf\"], .link[title='a.b']": { color: "red" },
+    }
+
+    let styles = LinkStyles::classes();
+    let expected = format!(
+        ".{}[href$=\".pdf\"], .{}[title='a.b'] {{\n  color: red;\n}}\n",
+        styles.link, styles.link
+    );
+    assert_eq!(expected, LinkStyles::stylesheet());
+}
+
+#[test]
+fn test_jss_nested_selectors() {
+    let css = jss!(
+        ".menu, .toolbar": {
+            display: "flex",
+            "&:hover, &.active": {
+                opacity: 1,
+            },
+            ".item": {
+                padding: "4px",
+                "& + &": {
+                    margin_left: "2px",
+                },
+            },
+        },
+    );
+
+    let expected = "\
+        .menu, .toolbar {\
+        \n  display: flex;\
+        \n}\
+        \n\
+        \n.menu:hover, .menu.active, .toolbar:hover, .toolbar.active {\
+        \n  opacity: 1;\
+        \n}\
+        \n\
+        \n.menu .item, .toolbar .item {\
+        \n  padding: 4px;\
+        \n}\
+        \n\
+        \n.menu .item + .menu .item, .toolbar .item + .toolbar .item {\
+        \n  margin-left: 2px;\
+        \n}\
+        \n";
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn test_jss_nested_with_dynamic_selector() {
+    let name = "frame";
+    let css = jss!(
+        format!(".{name}"): {
+            display: "block",
+            "&:hover": {
+                display: "none",
+            },
+        },
+    );
+    let expected = ".frame {\n  display: block;\n}\n\n.frame:hover {\n  display: none;\n}\n";
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn test_jss_nested_in_comma_separated_selectors() {
+    let expected = "\
+        a, b {\
+        \n  display: block;\
+        \n}\
+        \n\
+        \na > c, b > c {\
+        \n  display: none;\
+        \n}\
+        \n\
+        \na d, a:hover, b d, b:hover {\
+        \n  color: red;\
+        \n}\
+        \n";
+    let css = jss!(
+        "a, b": {
+            display: "block",
+            "& > c": {
+                display: "none",
+            },
+            "d, &:hover": {
+                color: "red",
+            },
+        },
+    );
+    assert_eq!(expected, css);
+
+    let tags = "a, b";
+    let css = jss!(
+        format!("{tags}"): {
+            display: "block",
+            "& > c": {
+                display: "none",
+            },
+            "d, &:hover": {
+                color: "red",
+            },
+        },
+    );
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn test_jss_nested_media_and_supports() {
+    let css = jss!(
+        "@supports (display: grid)": {
+            ".grid": {
+                display: "grid",
+                "@media (max-width: 800px)": {
+                    display: "block",
+                },
+            },
+        },
+    );
+
+    let expected = "\
+        @supports (display: grid) {\
+        \n.grid {\
+        \n  display: grid;\
+        \n}\
+        \n\
+        \n@media (max-width: 800px) {\
+        \n.grid {\
+        \n  display: block;\
+        \n}\
+        \n\
+        \n}\
+        \n\
+        \n}\
+        \n";
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn test_jss_font_face() {
+    let css = jss!(
+        "@font-face": {
+            font_family: "Fira Sans",
+            src: "url(fira.woff2) format(\"woff2\")",
+        },
+    );
+    let expected =
+        "@font-face {\n  font-family: Fira Sans;\n  src: url(fira.woff2) format(\"woff2\");\n}\n";
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn test_jss_scoped_nested() {
+    jss_scoped! {
+        struct MenuStyles;
+
+        menu: {
+            display: "flex",
+            "& > .item:hover": {
+                color: "red",
+            },
+        },
+    }
+
+    let styles = MenuStyles::classes();
+    let expected = format!(
+        ".{menu} {{\n  display: flex;\n}}\n\n.{menu} > .{item}:hover {{\n  color: red;\n}}\n",
+        menu = styles.menu,
+        item = styles.item,
+    );
+    assert_eq!(expected, MenuStyles::stylesheet());
+}
+
+#[test]
+fn test_jss_typed_values() {
+    let css = jss!(
+        ".panel": {
+            margin: px([10, 20]),
+            width: percent(50),
+            top: 0,
+            color: rgb(0, 0, 255),
+            transition_duration: ms(200),
+            rotate: deg(90),
+            height: "calc(100% - 10px)",
+        },
+    );
+    let expected = ".panel {\
+        \n  margin: 10px 20px;\
+        \n  width: 50%;\
+        \n  top: 0;\
+        \n  color: rgb(0, 0, 255);\
+        \n  transition-duration: 200ms;\
+        \n  rotate: 90deg;\
+        \n  height: calc(100% - 10px);\
+        \n}\
+        \n";
+    assert_eq!(expected, css);
+}
+
+#[test]
+fn test_quoted_property_is_not_type_checked() {
+    let css = jss!(
+        ".panel": {
+            "margin": rgb(255, 0, 0),
+        },
+    );
+    assert_eq!(".panel {\n  margin: rgb(255, 0, 0);\n}\n", css);
+}
@@ -1,6 +1,10 @@
 use sauron::html::lookup::match_tag;
+use sauron::svg::{attributes::XLINK_NAMESPACE, SVG_NAMESPACE};
 use sauron::vdom::Node;
-use sauron_html_parser::parse_html;
+use sauron_html_parser::{
+    parse_html, parse_html_with_diagnostics, parse_html_with_mode, raw_html, try_raw_html,
+    Diagnostic, Mode, ParseError, Position, MATHML_NAMESPACE,
+};
 
 #[test]
 fn should_match_tags() {
@@ -35,9 +39,273 @@ fn test_node_list() {
     <html>
         <body>This is body</body>
     </html>"#;
-    let expected = "<html><body>This is body</body></html>";
+    let expected = "<!doctype html><html><body>This is body</body></html>";
     let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
     println!("node: {:#?}", node);
     println!("render: {}", node.render_to_string());
     assert_eq!(expected, node.render_to_string());
 }
+
+#[test]
+fn keep_data_and_aria_attributes() {
+    let html = r#"<div data-id="42" aria-label="Close" foo="bar">x</div>"#;
+    let expected = r#"<div data-id="42" aria-label="Close">x</div>"#;
+    let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+    assert_eq!(expected, node.render_to_string());
+}
+
+#[test]
+fn keep_custom_elements() {
+    let html = r#"<my-widget size="large"><span>x</span></my-widget>"#;
+    let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+    assert_eq!(html, node.render_to_string());
+}
+
+#[test]
+fn keep_any_number_of_distinct_names() {
+    let html: String = (0..1100)
+        .map(|i| format!(r#"<b data-name{i}="x"></b>"#))
+        .collect();
+    let parsed = parse_html_with_diagnostics::<()>(&html, Mode::Lenient).expect("must parse");
+    assert!(parsed.diagnostics.is_empty());
+    let node: Node<()> = parsed.node.expect("must have a node");
+    assert_eq!(html, node.render_to_string());
+
+    // the names of the previous parse do not affect the next one
+    let html = r#"<x-last data-last="x"></x-last>"#;
+    let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+    assert_eq!(html, node.render_to_string());
+}
+
+#[test]
+fn strict_mode_rejects_unknowns() {
+    let custom = parse_html_with_mode::<()>("<my-widget></my-widget>", Mode::Strict);
+    assert!(matches!(custom, Err(ParseError::InvalidTag(tag, _)) if tag == "my-widget"));
+
+    let data = parse_html_with_mode::<()>(r#"<div><b data-id="42"></b></div>"#, Mode::Strict);
+    assert!(matches!(data, Err(ParseError::InvalidAttribute(attr, _)) if attr == "data-id"));
+}
+
+#[test]
+fn escaped_markup_stays_text() {
+    let html = "<p>&lt;script&gt;alert(1)&lt;/script&gt;</p>";
+    let node: Node<()> = raw_html(html);
+    assert!(!node.render_to_string().contains("<script>"));
+    assert_eq!(html, node.render_to_string());
+}
+
+#[test]
+fn decode_numeric_entities_into_text() {
+    let node: Node<()> = parse_html("<span>&#9650; up &#x25BC; down</span>")
+        .ok()
+        .flatten()
+        .expect("must parse");
+    assert_eq!("<span>▲ up ▼ down</span>", node.render_to_string());
+}
+
+#[test]
+fn decode_attribute_values() {
+    let html = r#"<a href="/search?q=&quot;a&quot;&amp;page=1" title="Tom &amp; Jerry">x</a>"#;
+    let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+    assert_eq!(html, node.render_to_string());
+}
+
+#[test]
+fn svg_elements_are_in_the_svg_namespace() {
+    let html = r##"<div><svg viewBox="0 0 10 10"><linearGradient id="g"></linearGradient><use xlink:href="#g"></use><foreignObject><p>x</p></foreignObject></svg></div>"##;
+    let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+    assert_eq!(html, node.render_to_string());
+
+    let div = node.element_ref().expect("must be an element");
+    assert_eq!(div.namespace(), None);
+    let svg = div.children()[0].element_ref().expect("must be an element");
+    assert_eq!(svg.namespace(), Some(&SVG_NAMESPACE));
+    assert_eq!(svg.attributes()[0].name(), &"viewBox");
+    let [gradient, use_, foreign_object] = svg.children() else {
+        panic!("expecting 3 children");
+    };
+    assert_eq!(gradient.tag(), Some("linearGradient"));
+    assert_eq!(
+        gradient.element_ref().unwrap().namespace(),
+        Some(&SVG_NAMESPACE)
+    );
+    let href = &use_.attributes().unwrap()[0];
+    assert_eq!(href.namespace(), Some(&XLINK_NAMESPACE));
+    let p = &foreign_object.children()[0];
+    assert_eq!(p.element_ref().unwrap().namespace(), None);
+}
+
+#[test]
+fn mathml_elements_are_in_the_mathml_namespace() {
+    let html = r#"<math display="block"><mi>x</mi></math>"#;
+    let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+    assert_eq!(html, node.render_to_string());
+    let math = node.element_ref().expect("must be an element");
+    assert_eq!(math.namespace(), Some(&MATHML_NAMESPACE));
+    assert_eq!(
+        math.children()[0].element_ref().unwrap().namespace(),
+        Some(&MATHML_NAMESPACE)
+    );
+}
+
+#[test]
+fn mathml_attributes_are_not_checked_in_strict_mode() {
+    let html = r#"<math display="block"><mi mathvariant="bold">x</mi></math>"#;
+    let node: Node<()> = parse_html_with_mode(html, Mode::Strict)
+        .ok()
+        .flatten()
+        .expect("must parse");
+    assert_eq!(html, node.render_to_string());
+}
+
+#[test]
+fn lenient_mode_collects_diagnostics() {
+    let html = "<div><blink>x</blink>\n<p foo=\"bar\">y</p></div>";
+    let parsed = parse_html_with_diagnostics::<()>(html, Mode::Lenient).expect("must parse");
+    // the children of the unknown tag are kept in its place
+    assert_eq!(
+        "<div>x<p>y</p></div>",
+        parsed.node.expect("must have a node").render_to_string()
+    );
+    assert_eq!(
+        parsed.diagnostics,
+        vec![
+            Diagnostic::UnknownTag {
+                tag: "blink".to_string(),
+                position: Position { line: 1, column: 6 },
+            },
+            Diagnostic::UnknownAttribute {
+                tag: "p".to_string(),
+                attribute: "foo".to_string(),
+                position: Position { line: 2, column: 4 },
+            },
+        ]
+    );
+}
+
+#[test]
+fn unknown_tag_is_unwrapped_along_with_the_diagnostics_inside() {
+    let html = r#"<blink><i foo="1">x</i></blink>"#;
+    let parsed = parse_html_with_diagnostics::<()>(html, Mode::Lenient).expect("must parse");
+    assert_eq!(
+        "<i>x</i>",
+        parsed.node.expect("must have a node").render_to_string()
+    );
+    assert_eq!(
+        parsed.diagnostics[1],
+        Diagnostic::UnknownAttribute {
+            tag: "i".to_string(),
+            attribute: "foo".to_string(),
+            position: Position {
+                line: 1,
+                column: 11
+            },
+        }
+    );
+}
+
+#[test]
+fn strict_mode_errors_have_positions() {
+    let result = parse_html_with_mode::<()>("<div>\n<blink></blink></div>", Mode::Strict);
+    assert!(matches!(
+        result,
+        Err(ParseError::InvalidTag(tag, Position { line: 2, column: 1 })) if tag == "blink"
+    ));
+}
+
+#[test]
+fn try_raw_html_does_not_panic() {
+    let node: Node<()> = try_raw_html("<b>bold</b>").expect("must parse");
+    assert_eq!("<b>bold</b>", node.render_to_string());
+}
+
+#[test]
+fn keep_comments_doctype_and_raw_text() {
+    let html = "<!doctype html><html><head><style>p > a { color: red; }</style><script>if (a < b && c) {}</script></head><body><!-- note --><textarea>&lt;b&gt;</textarea></body></html>";
+    let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+    assert_eq!(html, node.render_to_string());
+}
+
+#[test]
+fn textarea_and_title_content_is_decoded_but_never_parsed() {
+    use sauron::html::{symbol, text, textarea};
+    let node: Node<()> = parse_html("<textarea>&lt;b&gt; <i>&#9650;</i></textarea>")
+        .ok()
+        .flatten()
+        .expect("must parse");
+    let expected: Node<()> = textarea(
+        [],
+        [
+            symbol("&lt;"),
+            text("b"),
+            symbol("&gt;"),
+            text(" "),
+            symbol("&lt;"),
+            text("i"),
+            symbol("&gt;"),
+            text("\u{25b2}"),
+            symbol("&lt;"),
+            text("/i"),
+            symbol("&gt;"),
+        ],
+    );
+    assert_eq!(expected, node);
+
+    let node: Node<()> = parse_html("<title>Tom &amp; <b>Jerry</b></title>")
+        .ok()
+        .flatten()
+        .expect("must parse");
+    assert_eq!(
+        "<title>Tom &amp; &lt;b&gt;Jerry&lt;/b&gt;</title>",
+        node.render_to_string()
+    );
+    assert_eq!(node.children().len(), 10);
+}
+
+#[test]
+fn rcdata_content_is_the_same_after_rendering_and_parsing_again() {
+    for html in [
+        "<textarea><img src=x onerror=alert(1)> &amp; &lt;b&gt;</textarea>",
+        "<title>Tom &amp; <b>Jerry</b></title>",
+    ] {
+        let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+        let rendered = node.render_to_string();
+        assert!(!rendered.contains("<img") && !rendered.contains("<b>"));
+        let reparsed: Node<()> = parse_html(&rendered).ok().flatten().expect("must parse");
+        assert_eq!(node, reparsed);
+        assert_eq!(rendered, reparsed.render_to_string());
+    }
+}
+
+#[test]
+fn inline_style_is_parsed_into_styles() {
+    use sauron::html::{attributes::styles, div};
+    let node: Node<()> = parse_html(r#"<div style="Color: red;  margin: 0   4px;"></div>"#)
+        .ok()
+        .flatten()
+        .expect("must parse");
+    let expected: Node<()> = div([styles([("color", "red"), ("margin", "0 4px")])], []);
+    assert_eq!(expected, node);
+}
+
+#[test]
+fn parsed_html_is_semantically_equal_to_the_view() {
+    use sauron::html::{attributes::class, div, footer, text};
+    let html = r#"<article class="side-to-side  main">
+    <div>
+        This is div content1
+    </div>
+    <footer>
+        This is footer
+    </footer>
+</article>"#;
+    let node: Node<()> = parse_html(html).ok().flatten().expect("must parse");
+    let view: Node<()> = sauron::html::article(
+        [class("main"), class("side-to-side")],
+        [
+            div([], [text("This is div content1")]),
+            footer([], [text("This is footer")]),
+        ],
+    );
+    assert!(node.semantic_eq(&view));
+}
@@ -0,0 +1,166 @@
+use sauron::html::attributes::styles;
+use sauron::html::tags::style;
+use sauron::vdom::Node;
+use sauron::*;
+use sauron_html_parser::sanitize::{sanitize, sanitize_html, Policy, Stripped};
+
+#[test]
+fn default_policy_keeps_formatting() {
+    let node: Node<()> = p(
+        [class("intro"), title("greeting")],
+        [text("Hello "), strong([], [text("world")])],
+    );
+    let sanitized = sanitize(&node, &Policy::default());
+    assert_eq!(
+        sanitized.node.render_to_string(),
+        r#"<p title="greeting">Hello <strong>world</strong></p>"#
+    );
+    assert_eq!(
+        sanitized.stripped,
+        vec![Stripped::Attribute {
+            tag: "p".to_string(),
+            attribute: "class".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn strip_scripts_and_unsafe_urls() {
+    let node: Node<()> = div(
+        [],
+        [
+            a([href("javascript:alert(1)")], [text("click")]),
+            a([href(" JAVA\tSCRIPT:alert(1)")], [text("click")]),
+            a([href("https://example.com")], [text("safe")]),
+            a([href("/relative")], [text("relative")]),
+            script([], [text("alert(1)")]),
+            style([], [text("p { color: red; }")]),
+            comment("hidden"),
+        ],
+    );
+    let sanitized = sanitize(&node, &Policy::default());
+    assert_eq!(
+        sanitized.node.render_to_string(),
+        r#"<div><a>click</a><a>click</a><a href="https://example.com">safe</a><a href="/relative">relative</a></div>"#
+    );
+    assert_eq!(sanitized.stripped.len(), 5);
+    assert_eq!(
+        sanitized.stripped[0],
+        Stripped::Url {
+            tag: "a".to_string(),
+            attribute: "href".to_string(),
+            url: "javascript:alert(1)".to_string(),
+        }
+    );
+    assert_eq!(sanitized.stripped[2], Stripped::Tag("script".to_string()));
+    assert_eq!(sanitized.stripped[4], Stripped::Comment);
+}
+
+#[test]
+fn unknown_tags_are_unwrapped() {
+    let node: Node<()> = div([], [form([], [span([], [text("inside")])])]);
+    let sanitized = sanitize(&node, &Policy::default());
+    assert_eq!(
+        sanitized.node.render_to_string(),
+        "<div><span>inside</span></div>"
+    );
+    assert_eq!(sanitized.stripped, vec![Stripped::Tag("form".to_string())]);
+}
+
+#[test]
+fn custom_policy_with_styles() {
+    let policy = Policy::new()
+        .allow_tags(["span"])
+        .allow_attributes("span", ["style"])
+        .allow_style_properties(["color"]);
+    let node: Node<()> = span(
+        [styles([
+            ("color", "red"),
+            ("background", "url(https://example.com/track)"),
+        ])],
+        [text("red")],
+    );
+    let sanitized = sanitize(&node, &policy);
+    assert_eq!(
+        sanitized.node.render_to_string(),
+        r#"<span style="color:red;">red</span>"#
+    );
+    assert_eq!(
+        sanitized.stripped,
+        vec![Stripped::Style {
+            tag: "span".to_string(),
+            property: "background".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn markup_in_unwrapped_textarea_and_title_is_not_live() {
+    for html in [
+        "<textarea><img src=x onerror=alert(1)></textarea>",
+        "<title><img src=x onerror=alert(1)></title>",
+    ] {
+        let sanitized = sanitize_html::<()>(html, &Policy::default()).expect("must parse");
+        assert_eq!(
+            sanitized.node.render_to_string(),
+            "&lt;img src=x onerror=alert(1)&gt;"
+        );
+    }
+    let node: Node<()> = p([], [text("1 < 2 && <b>")]);
+    let sanitized = sanitize(&node, &Policy::default());
+    assert_eq!(
+        sanitized.node.render_to_string(),
+        "<p>1 &lt; 2 &amp;&amp; &lt;b&gt;</p>"
+    );
+}
+
+#[test]
+fn css_escaped_urls_are_stripped() {
+    let policy = Policy::new()
+        .allow_tags(["span"])
+        .allow_attributes("span", ["style"])
+        .allow_style_properties(["background"]);
+    for value in [
+        r"\75rl(https://example.com/track)",
+        r"\000075 rl(https://example.com/track)",
+        r"u\rl(https://example.com/track)",
+        r"\65xpression(alert(1))",
+    ] {
+        let node: Node<()> = span([styles([("background", value)])], []);
+        let sanitized = sanitize(&node, &policy);
+        assert_eq!(sanitized.node.render_to_string(), "<span></span>");
+        assert_eq!(
+            sanitized.stripped,
+            vec![Stripped::Style {
+                tag: "span".to_string(),
+                property: "background".to_string(),
+            }]
+        );
+    }
+}
+
+#[test]
+fn every_url_in_srcset_is_checked() {
+    let policy = Policy::default().allow_attributes("img", ["srcset"]);
+    let node: Node<()> = img(
+        [attr("srcset", "/a.png 1x, https://example.com/b.png 2x")],
+        [],
+    );
+    let sanitized = sanitize(&node, &policy);
+    assert_eq!(
+        sanitized.node.render_to_string(),
+        r#"<img srcset="/a.png 1x, https://example.com/b.png 2x"/>"#
+    );
+
+    let node: Node<()> = img([attr("srcset", "/a.png 1x, javascript:alert(1) 2x")], []);
+    let sanitized = sanitize(&node, &policy);
+    assert_eq!(sanitized.node.render_to_string(), "<img/>");
+    assert_eq!(
+        sanitized.stripped,
+        vec![Stripped::Url {
+            tag: "img".to_string(),
+            attribute: "srcset".to_string(),
+            url: "/a.png 1x, javascript:alert(1) 2x".to_string(),
+        }]
+    );
+}
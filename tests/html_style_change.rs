@@ -16,7 +16,7 @@ fn style_calcd_changed() {
     let patches: Vec<Patch<&'static str>> = diff(&old, &new);
     let styl = style! {width: format!("calc(50% + {}", px(200))};
     let expected: Vec<Patch<&'static str>> =
-        vec![Patch::add_attributes(&"div", TreePath::new([]), [&styl])];
+        vec![Patch::add_attributes("div", TreePath::new([]), [&styl])];
     assert_eq!(expected, patches);
 }
 
@@ -38,7 +38,7 @@ fn style_calcd_changed_with_event() {
 
     let patches: Vec<Patch<()>> = diff(&old, &new);
     let styl = style! {width: format!("calc(50% + {}", px(200))};
-    let expected: Vec<Patch<()>> = vec![Patch::add_attributes(&"div", TreePath::new([]), [&styl])];
+    let expected: Vec<Patch<()>> = vec![Patch::add_attributes("div", TreePath::new([]), [&styl])];
     assert_eq!(expected, patches);
 }
 
@@ -95,8 +95,8 @@ fn app_editor_width_allocation_bug() {
     let styl_1 = style! {width: format!("calc(50% + {}", px(200))};
     let styl_2 = style! {width: format!("calc(50% - {}", px(200))};
     let expected: Vec<Patch<()>> = vec![
-        Patch::add_attributes(&"div", TreePath::new([0]), [&styl_1]),
-        Patch::add_attributes(&"div", TreePath::new([2]), [&styl_2]),
+        Patch::add_attributes("div", TreePath::new([0]), [&styl_1]),
+        Patch::add_attributes("div", TreePath::new([2]), [&styl_2]),
     ];
     assert_eq!(expected, patches);
 }
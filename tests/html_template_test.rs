@@ -0,0 +1,98 @@
+#![deny(warnings)]
+use sauron::svg::{attributes::XLINK_NAMESPACE, SVG_NAMESPACE};
+use sauron::*;
+
+#[allow(unused)]
+enum Msg {
+    Close,
+    Input(String),
+}
+
+#[test]
+fn template_with_placeholders() {
+    let name = "Sauron";
+    let view: Node<Msg> =
+        html_template!(r#"<div id="greeting" data-name="{{ name }}">Hello {{ name }}</div>"#);
+    let expected: Node<Msg> = div(
+        [id("greeting"), attr("data-name", name)],
+        [text("Hello Sauron")],
+    );
+    assert_eq!(expected, view);
+}
+
+#[test]
+fn template_with_mixed_attribute_value() {
+    let count = 3;
+    let view: Node<Msg> = html_template!(r#"<span class="badge badge-{{ count }}"></span>"#);
+    let expected: Node<Msg> = span([class("badge badge-3")], []);
+    assert_eq!(expected, view);
+}
+
+#[test]
+fn template_with_event_listener() {
+    let view: Node<Msg> = html_template!(
+        r#"<input type="text" on_input="{{ |e: InputEvent| Msg::Input(e.value()) }}"/>"#
+    );
+    let expected: Node<Msg> = input(
+        [
+            r#type("text"),
+            on_input(|e: InputEvent| Msg::Input(e.value())),
+        ],
+        [],
+    );
+    assert_eq!(expected.render_to_string(), view.render_to_string());
+    let listener = view.attribute_value("input").expect("must have a listener");
+    assert!(listener[0].as_event_listener().is_some());
+}
+
+#[test]
+fn template_with_multiple_root_nodes() {
+    let view: Node<Msg> = html_template!("<li>one</li><li>two</li>");
+    let expected: Node<Msg> = html::node_list([li([], [text("one")]), li([], [text("two")])]);
+    assert_eq!(expected, view);
+}
+
+#[test]
+fn template_from_file() {
+    let title = "Notice";
+    let variant = "warning";
+    let body: Node<Msg> = strong([], [text("Read me")]);
+    let view: Node<Msg> = include_html!("tests/templates/card.html");
+    let expected: Node<Msg> = article(
+        [class("card")],
+        [
+            h1([], [text("Notice")]),
+            comment(" the body of the card "),
+            p(
+                [class("card-body warning")],
+                [strong([], [text("Read me")])],
+            ),
+            button([on_click(|_| Msg::Close), disabled(true)], [text("Close")]),
+        ],
+    );
+    assert_eq!(expected.render_to_string(), view.render_to_string());
+}
+
+#[test]
+fn template_with_svg_is_the_same_as_the_parsed_html() {
+    let html = r##"<div><svg viewBox="0 0 10 10"><linearGradient id="g"></linearGradient><use xlink:href="#g"></use><foreignObject><p>x</p></foreignObject></svg></div>"##;
+    let view: Node<Msg> = html_template!(
+        r##"<div><svg viewBox="0 0 10 10"><linearGradient id="g"></linearGradient><use xlink:href="#g"></use><foreignObject><p>x</p></foreignObject></svg></div>"##
+    );
+    let parsed: Node<Msg> = parse_html(html).ok().flatten().expect("must parse");
+    assert_eq!(parsed, view);
+    assert_eq!(html, view.render_to_string());
+
+    let svg = view.element_ref().unwrap().children()[0]
+        .element_ref()
+        .expect("must be an element");
+    assert_eq!(svg.namespace(), Some(&SVG_NAMESPACE));
+    let [gradient, use_, foreign_object] = svg.children() else {
+        panic!("expecting 3 children");
+    };
+    assert_eq!(gradient.tag(), Some("linearGradient"));
+    let href = &use_.attributes().unwrap()[0];
+    assert_eq!(href.namespace(), Some(&XLINK_NAMESPACE));
+    let p = &foreign_object.children()[0];
+    assert_eq!(p.element_ref().unwrap().namespace(), None);
+}
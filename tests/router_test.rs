@@ -0,0 +1,108 @@
+use sauron::dom::router::{encode, Route, Router, Url};
+
+#[derive(Debug, PartialEq)]
+enum Page {
+    Home,
+    Article { year: u16, slug: String },
+    File(String),
+    Search { q: String, page: u32 },
+}
+
+impl Route for Page {
+    fn from_url(url: &str) -> Option<Self> {
+        Router::new()
+            .route("/", |_| Some(Page::Home))
+            .route("/articles/:year/:slug", |params| {
+                Some(Page::Article {
+                    year: params.parse("year")?,
+                    slug: params.get("slug")?.to_string(),
+                })
+            })
+            .route("/files/*path", |params| {
+                Some(Page::File(params.get("path")?.to_string()))
+            })
+            .route("/search", |params| {
+                Some(Page::Search {
+                    q: params.query("q")?.to_string(),
+                    page: params
+                        .query("page")
+                        .and_then(|p| p.parse().ok())
+                        .unwrap_or(1),
+                })
+            })
+            .recognize(url)
+    }
+
+    fn to_url(&self) -> String {
+        match self {
+            Page::Home => "/".to_string(),
+            Page::Article { year, slug } => format!("/articles/{year}/{}", encode(slug)),
+            Page::File(path) => format!("/files/{path}"),
+            Page::Search { q, page } => format!("/search?q={}&page={page}", encode(q)),
+        }
+    }
+}
+
+#[test]
+fn match_path_parameters() {
+    assert_eq!(Page::from_url(""), Some(Page::Home));
+    assert_eq!(Page::from_url("/"), Some(Page::Home));
+    assert_eq!(
+        Page::from_url("/articles/2024/hello-world/"),
+        Some(Page::Article {
+            year: 2024,
+            slug: "hello-world".to_string()
+        })
+    );
+    assert_eq!(Page::from_url("/articles/next/hello-world"), None);
+    assert_eq!(Page::from_url("/articles/2024"), None);
+    assert_eq!(Page::from_url("/unknown"), None);
+}
+
+#[test]
+fn match_the_rest_of_the_path() {
+    assert_eq!(
+        Page::from_url("/files/docs/a%20b.txt"),
+        Some(Page::File("docs/a b.txt".to_string()))
+    );
+    assert_eq!(Page::from_url("/files"), Some(Page::File(String::new())));
+}
+
+#[test]
+fn match_query_parameters() {
+    assert_eq!(
+        Page::from_url("/search?q=rust+wasm&page=3#results"),
+        Some(Page::Search {
+            q: "rust wasm".to_string(),
+            page: 3
+        })
+    );
+    assert_eq!(Page::from_url("/search"), None);
+}
+
+#[test]
+fn routes_round_trip() {
+    let routes = [
+        Page::Home,
+        Page::Article {
+            year: 2024,
+            slug: "über & more".to_string(),
+        },
+        Page::Search {
+            q: "a=b&c".to_string(),
+            page: 2,
+        },
+    ];
+    for route in routes {
+        assert_eq!(Page::from_url(&route.to_url()), Some(route));
+    }
+}
+
+#[test]
+fn parse_full_url() {
+    let url = Url::parse("https://example.com/users/42?tab=posts&sort#top");
+    assert_eq!(url.segments, vec!["users", "42"]);
+    assert_eq!(url.query("tab"), Some("posts"));
+    assert_eq!(url.query("sort"), Some(""));
+    assert_eq!(url.fragment.as_deref(), Some("top"));
+}
@@ -21,7 +21,7 @@ fn class_changed() {
     assert_eq!(
         diff,
         vec![Patch::add_attributes(
-            &"main",
+            "main",
             TreePath::new(vec![]),
             vec![&attr("class", "class2")]
         )]
@@ -55,7 +55,7 @@ fn parent_of_matching_keyed_are_ignored() {
     assert_eq!(
         patches,
         vec![Patch::add_attributes(
-            &"ul",
+            "ul",
             TreePath::new(vec![]),
             vec![&attr("class", "changed")]
         )],
@@ -38,7 +38,7 @@ fn key_1_removed_at_start() {
     let diff = diff(&old, &new);
     assert_eq!(
         diff,
-        vec![Patch::remove_node(Some(&"div"), TreePath::new(vec![0]))]
+        vec![Patch::remove_node(Some("div"), TreePath::new(vec![0]))]
     );
 }
 
@@ -62,7 +62,7 @@ fn non_unique_keys_matched_at_old() {
     let diff = diff(&old, &new);
     assert_eq!(
         diff,
-        vec![Patch::remove_node(Some(&"div"), TreePath::new(vec![1]))]
+        vec![Patch::remove_node(Some("div"), TreePath::new(vec![1]))]
     );
 }
 
@@ -86,7 +86,7 @@ fn key_2_removed_at_the_end() {
     let diff = diff(&old, &new);
     assert_eq!(
         diff,
-        vec![Patch::remove_node(Some(&"div"), TreePath::new(vec![1]),)]
+        vec![Patch::remove_node(Some("div"), TreePath::new(vec![1]),)]
     );
 }
 
@@ -114,7 +114,7 @@ fn key_2_removed_at_the_middle() {
     let diff = diff(&old, &new);
     assert_eq!(
         diff,
-        vec![Patch::remove_node(Some(&"div"), TreePath::new(vec![1]))]
+        vec![Patch::remove_node(Some("div"), TreePath::new(vec![1]))]
     );
 }
 
@@ -148,7 +148,7 @@ fn there_are_2_exact_same_keys_in_the_old() {
         diff,
         vec![
             Patch::replace_node(None, TreePath::new(vec![0, 0]), vec![&leaf("1")]),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![1]))
+            Patch::remove_node(Some("div"), TreePath::new(vec![1]))
         ]
     );
 }
@@ -184,7 +184,7 @@ fn there_are_2_exact_same_keys_in_the_new() {
         vec![
             Patch::replace_node(None, TreePath::new([0, 0]), vec![&leaf("1")]),
             Patch::insert_after_node(
-                Some(&"div"),
+                Some("div"),
                 TreePath::new([0]),
                 vec![&element("div", vec![attr("key", "1")], vec![leaf("1")])]
             ),
@@ -225,11 +225,11 @@ fn there_are_2_exact_same_keys_in_both_old_and_new() {
             Patch::replace_node(None, TreePath::new(vec![0, 0]), vec![&leaf("1")]),
             Patch::replace_node(None, TreePath::new(vec![1, 0]), vec![&leaf("3")]),
             Patch::insert_before_node(
-                Some(&"div"),
+                Some("div"),
                 TreePath::new(vec![1]),
                 vec![&element("div", vec![attr("key", "1")], vec![leaf("2")])]
             ),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![2])),
+            Patch::remove_node(Some("div"), TreePath::new(vec![2])),
         ]
     );
 }
@@ -257,7 +257,7 @@ fn key_2_inserted_at_start() {
     assert_eq!(
         diff,
         vec![Patch::insert_before_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![0]),
             vec![&element("div", vec![attr("key", "2")], vec![])]
         )]
@@ -284,7 +284,7 @@ fn keyed_element_not_reused() {
     assert_eq!(
         diff,
         vec![Patch::replace_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![0]),
             vec![&element("div", vec![attr("key", "2")], vec![])]
         )]
@@ -316,7 +316,7 @@ fn key_2_inserted_at_the_end() {
     assert_eq!(
         diff,
         vec![Patch::append_children(
-            Some(&"main"),
+            Some("main"),
             TreePath::new(vec![]),
             vec![&element("div", vec![attr("key", "2")], vec![])]
         )]
@@ -354,7 +354,7 @@ fn test_append_at_sub_level() {
     assert_eq!(
         diff,
         vec![Patch::append_children(
-            Some(&"main"),
+            Some("main"),
             TreePath::new(vec![0]),
             vec![
                 &element("div", vec![attr("key", "2")], vec![leaf("2")]),
@@ -392,7 +392,7 @@ fn key_2_inserted_in_the_middle() {
     assert_eq!(
         diff,
         vec![Patch::insert_after_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![0]),
             vec![&element("div", vec![attr("key", "2")], vec![])]
         )]
@@ -427,11 +427,11 @@ fn key1_removed_at_start_then_key2_has_additional_attributes() {
         diff,
         vec![
             Patch::add_attributes(
-                &"div",
+                "div",
                 TreePath::new(vec![1]),
                 vec![&attr("class", "some-class")]
             ),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![0]),),
+            Patch::remove_node(Some("div"), TreePath::new(vec![0]),),
         ]
     );
 }
@@ -471,11 +471,11 @@ fn deep_nested_key1_removed_at_start_then_key2_has_additional_attributes() {
         diff,
         vec![
             Patch::add_attributes(
-                &"div",
+                "div",
                 TreePath::new(vec![0, 1]),
                 vec![&attr("class", "some-class")]
             ),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![0, 0]),),
+            Patch::remove_node(Some("div"), TreePath::new(vec![0, 0]),),
         ]
     );
 }
@@ -516,12 +516,12 @@ fn deep_nested_more_children_key0_and_key1_removed_at_start_then_key2_has_additi
         diff,
         vec![
             Patch::add_attributes(
-                &"div",
+                "div",
                 TreePath::new(vec![0, 2]),
                 vec![&attr("class", "some-class")]
             ),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![0, 0]),),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![0, 1]),),
+            Patch::remove_node(Some("div"), TreePath::new(vec![0, 0]),),
+            Patch::remove_node(Some("div"), TreePath::new(vec![0, 1]),),
         ]
     );
 }
@@ -576,7 +576,7 @@ fn deep_nested_keyed_with_non_keyed_children() {
         diff,
         vec![
             Patch::add_attributes(
-                &"div",
+                "div",
                 TreePath::new(vec![0, 2]),
                 vec![&attr("class", "some-class")]
             ),
@@ -590,8 +590,8 @@ fn deep_nested_keyed_with_non_keyed_children() {
                 TreePath::new(vec![0, 2, 1, 0]),
                 vec![&leaf("Click here to continue")]
             ),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![0, 0]),),
-            Patch::remove_node(Some(&"div"), TreePath::new(vec![0, 1]),),
+            Patch::remove_node(Some("div"), TreePath::new(vec![0, 0]),),
+            Patch::remove_node(Some("div"), TreePath::new(vec![0, 1]),),
         ]
     );
 }
@@ -641,7 +641,7 @@ fn text_changed_in_keyed_elements() {
                 TreePath::new(vec![0, 2, 0]),
                 vec![&leaf("item3 with changes")]
             ),
-            Patch::remove_node(Some(&"article"), TreePath::new(vec![0, 0])),
+            Patch::remove_node(Some("article"), TreePath::new(vec![0, 0])),
         ]
     );
 }
@@ -696,7 +696,7 @@ fn text_changed_in_mixed_keyed_and_non_keyed_elements() {
                 TreePath::new(vec![0, 2, 0]),
                 vec![&leaf("item3 with changes")]
             ),
-            Patch::remove_node(Some(&"article"), TreePath::new(vec![0, 0]),),
+            Patch::remove_node(Some("article"), TreePath::new(vec![0, 0]),),
             Patch::replace_node(None, TreePath::new(vec![1, 0]), vec![&leaf("2 items left")]),
         ]
     );
@@ -755,7 +755,7 @@ fn test12() {
                 TreePath::new(vec![1, 2, 0]),
                 vec![&leaf("item3 with changes")]
             ),
-            Patch::remove_node(Some(&"article"), TreePath::new(vec![1, 0]),),
+            Patch::remove_node(Some("article"), TreePath::new(vec![1, 0]),),
             Patch::replace_node(None, TreePath::new(vec![2, 0]), vec![&leaf("2 items left")]),
         ]
     );
@@ -786,6 +786,6 @@ fn remove_first() {
     dbg!(&diff);
     assert_eq!(
         diff,
-        vec![Patch::remove_node(Some(&"div"), TreePath::new(vec![0]),)]
+        vec![Patch::remove_node(Some("div"), TreePath::new(vec![0]),)]
     )
 }
@@ -34,6 +34,6 @@ fn test_node_list() {
     let diff = diff(&old, &new);
     assert_eq!(
         diff,
-        vec![Patch::remove_node(Some(&"li"), TreePath::new(vec![2]),)],
+        vec![Patch::remove_node(Some("li"), TreePath::new(vec![2]),)],
     );
 }
@@ -10,7 +10,7 @@ fn test_replace_node() {
     assert_eq!(
         diff,
         vec![Patch::replace_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![]),
             vec![&new]
         )],
@@ -38,7 +38,7 @@ fn test_replace_node_in_child() {
     assert_eq!(
         diff,
         vec![Patch::replace_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![0]),
             vec![&element("span", vec![], vec![])]
         )],
@@ -73,9 +73,9 @@ fn test_205() {
     assert_eq!(
         dbg!(diff(&old, &new)),
         vec![
-            Patch::remove_node(Some(&"i"), TreePath::new(vec![0, 1]),),
+            Patch::remove_node(Some("i"), TreePath::new(vec![0, 1]),),
             Patch::replace_node(
-                Some(&"b"),
+                Some("b"),
                 TreePath::new(vec![1]),
                 vec![&element("i", vec![], vec![])]
             ),
@@ -137,7 +137,7 @@ fn test_class_changed() {
     assert_eq!(
         diff,
         vec![Patch::add_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![&attr("class", "some-class2")]
         )]
@@ -184,7 +184,7 @@ fn test_class_will_not_be_merged_on_different_calls() {
     assert_ne!(
         diff,
         vec![Patch::add_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![&Attribute::with_multiple_values(
                 None,
@@ -209,7 +209,7 @@ fn test_class_removed() {
     assert_eq!(
         diff,
         vec![Patch::remove_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![&attr("class", "some-class")]
         )]
@@ -240,7 +240,7 @@ fn test_multiple_calls_to_style() {
     assert_eq!(
         diff,
         vec![Patch::add_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![
                 &attr("style", "display:flex"),
@@ -260,7 +260,7 @@ fn inner_html_func_calls() {
     assert_eq!(
         diff,
         vec![Patch::add_attributes(
-            &"div",
+            "div",
             TreePath::new(vec![]),
             vec![&attr("inner_html", "<h1>Hello</h2>")]
         )]
@@ -288,7 +288,7 @@ fn test_append() {
     assert_eq!(
         diff,
         vec![Patch::append_children(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![]),
             vec![&element("div", vec![], vec![leaf("2")])],
         )]
@@ -317,7 +317,7 @@ fn test_append_more() {
     assert_eq!(
         diff,
         vec![Patch::append_children(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![]),
             vec![
                 &element("div", vec![], vec![leaf("2")]),
@@ -358,7 +358,7 @@ fn test_append_at_sub_level() {
     assert_eq!(
         diff,
         vec![Patch::append_children(
-            Some(&"main"),
+            Some("main"),
             TreePath::new(vec![0]),
             vec![
                 &element("div", vec![], vec![leaf("2")]),
@@ -31,7 +31,7 @@ fn using_fragments() {
     assert_eq!(
         diff,
         vec![Patch::insert_before_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![0]),
             vec![&element(
                 "div",
@@ -28,7 +28,7 @@ fn insert_on_deep_level_keyed() {
     assert_eq!(
         diff,
         vec![Patch::insert_after_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![0]),
             vec![&element("div", vec![attr("key", "2")], vec![leaf("1")])]
         ),]
@@ -77,7 +77,7 @@ fn insert_on_deep_multi_level_level_keyed() {
     assert_eq!(
         diff,
         vec![Patch::insert_after_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![1, 0]),
             vec![&element("div", vec![attr("key", "b")], vec![])]
         ),]
@@ -126,7 +126,7 @@ fn insert_on_deep_multi_level_keyed_non_keyed_keyed() {
     assert_eq!(
         diff,
         vec![Patch::insert_after_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![1, 0]),
             vec![&element("div", vec![attr("key", "b")], vec![])]
         ),]
@@ -161,7 +161,7 @@ fn insert_on_deep_level_non_keyed_container() {
     assert_eq!(
         diff,
         vec![Patch::insert_after_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![0]),
             vec![&element("div", vec![attr("key", "2")], vec![leaf("1")])]
         ),]
@@ -39,7 +39,7 @@ fn key_inserted_at_start() {
     assert_eq!(
         diff,
         vec![Patch::insert_before_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![0]),
             vec![&element(
                 "div",
@@ -91,7 +91,7 @@ fn key_inserted_at_middle() {
     assert_eq!(
         diff,
         vec![Patch::insert_after_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![4]),
             vec![&element(
                 "div",
@@ -150,7 +150,7 @@ fn wrapped_elements() {
     assert_eq!(
         diff,
         vec![Patch::insert_after_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new(vec![0, 4]),
             vec![&element(
                 "div",
@@ -359,7 +359,7 @@ fn insert_one_line_at_start() {
             Patch::replace_node(None, TreePath::new(vec![0, 1, 0, 0]), vec![&leaf("3")]),
             Patch::replace_node(None, TreePath::new(vec![0, 0, 0, 0]), vec![&leaf("2")]),
             Patch::insert_before_node(
-                Some(&"div"),
+                Some("div"),
                 TreePath::new(vec![0, 0]),
                 vec![&element(
                     "div",
@@ -472,7 +472,7 @@ fn insert_two_lines_at_start() {
             Patch::replace_node(None, TreePath::new(vec![0, 1, 0, 0]), vec![&leaf("4")]),
             Patch::replace_node(None, TreePath::new(vec![0, 0, 0, 0]), vec![&leaf("3")]),
             Patch::insert_before_node(
-                Some(&"div"),
+                Some("div"),
                 TreePath::new(vec![0, 0]),
                 vec![
                     &element(
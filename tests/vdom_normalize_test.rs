@@ -0,0 +1,106 @@
+use sauron::html::attributes::{attr, styles};
+use sauron::html::node_list;
+use sauron::*;
+
+#[test]
+fn whitespace_text_is_collapsed() {
+    let parsed: Node<()> = ul(
+        [],
+        [
+            text("\n    "),
+            li([], [text("\n  one  "), text(" item\n")]),
+            text("\n"),
+        ],
+    );
+    let built: Node<()> = ul([], [li([], [text("one item")])]);
+    assert_ne!(parsed, built);
+    assert!(parsed.semantic_eq(&built));
+}
+
+#[test]
+fn whitespace_in_pre_is_kept() {
+    let parsed: Node<()> = pre([], [text("  a\n  b")]);
+    let built: Node<()> = pre([], [text("a b")]);
+    assert!(!parsed.semantic_eq(&built));
+    assert_eq!(parsed.clone().normalize(), parsed);
+}
+
+#[test]
+fn node_lists_are_flatten() {
+    let parsed: Node<()> = div(
+        [],
+        [node_list([
+            span([], [text("a")]),
+            node_list([span([], [])]),
+        ])],
+    );
+    let built: Node<()> = div([], [span([], [text("a")]), span([], [])]);
+    assert!(parsed.semantic_eq(&built));
+    assert!(node_list::<()>([built.clone()]).semantic_eq(&built));
+}
+
+#[test]
+fn attributes_are_sorted_and_merged() {
+    let parsed: Node<()> = input(
+        [
+            r#type("text"),
+            class("b a"),
+            attr("size", "10"),
+            disabled(true),
+        ],
+        [],
+    );
+    let built: Node<()> = input(
+        [
+            disabled(true),
+            attr("size", 10),
+            class("a"),
+            r#type("text"),
+            class("b"),
+            class("a"),
+        ],
+        [],
+    );
+    assert!(parsed.semantic_eq(&built));
+    assert!(!parsed.semantic_eq(&input([class("a"), r#type("text")], [])));
+}
+
+#[test]
+fn styles_are_sorted_and_last_one_wins() {
+    let parsed: Node<()> = div([styles([("margin", "0"), ("color", "red")])], []);
+    let built: Node<()> = div(
+        [
+            styles([("color", "blue"), ("margin", "0")]),
+            styles([("color", "red")]),
+        ],
+        [],
+    );
+    assert!(parsed.semantic_eq(&built));
+}
+
+#[test]
+fn whitespace_next_to_inline_elements_is_kept() {
+    let spaced: Node<()> = p([], [text("Hello  \n"), b([], [text("x")])]);
+    let joined: Node<()> = p([], [text("Hello"), b([], [text("x")])]);
+    assert!(!spaced.semantic_eq(&joined));
+    assert!(spaced.semantic_eq(&p([], [text("Hello "), b([], [text("x")])])));
+
+    let spaced: Node<()> = p([], [b([], [text("a")]), text("\n  "), i([], [text("b")])]);
+    let joined: Node<()> = p([], [b([], [text("a")]), i([], [text("b")])]);
+    assert!(!spaced.semantic_eq(&joined));
+}
+
+#[test]
+fn whitespace_next_to_block_elements_is_removed() {
+    let parsed: Node<()> = div(
+        [],
+        [
+            div([], [text("a")]),
+            text("\n  "),
+            div([], [text("b")]),
+            text(" c"),
+        ],
+    );
+    let built: Node<()> = div([], [div([], [text("a")]), div([], [text("b")]), text("c")]);
+    assert!(parsed.semantic_eq(&built));
+}
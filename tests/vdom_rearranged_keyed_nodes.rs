@@ -28,7 +28,7 @@ fn text_changed_keyed() {
     assert_eq!(
         diff,
         vec![Patch::move_before_node(
-            Some(&"div"),
+            Some("div"),
             TreePath::new([0]),
             [TreePath::new([2]), TreePath::new([1])]
         )]
@@ -48,7 +48,7 @@ fn key_lis_1_to_9() {
         diff,
         vec![
             Patch::insert_after_node(
-                Some(&"div"),
+                Some("div"),
                 TreePath::new(vec![8]),
                 vec![
                     &element("div", vec![attr("key", "XXX4")], vec![leaf("lineXXX")]),
@@ -57,7 +57,7 @@ fn key_lis_1_to_9() {
                 ]
             ),
             Patch::insert_before_node(
-                Some(&"div"),
+                Some("div"),
                 TreePath::new(vec![0]),
                 vec![
                     &element("div", vec![attr("key", "XXX1")], vec![leaf("lineXXX")]),
@@ -0,0 +1,63 @@
+use sauron::vdom::{Leaf, TemplatedView};
+use sauron::*;
+
+trait View {
+    type MSG;
+    fn view(&self) -> Node<Self::MSG>;
+}
+
+struct Counter {
+    count: i32,
+}
+
+impl View for Counter {
+    type MSG = ();
+
+    view! {
+        <div class="counter">
+            <span class="label">"count:"</span>
+            <span class=format!("value-{}", self.count)>{text(self.count)}</span>
+        </div>
+    }
+}
+
+fn templated_view<MSG>(node: &Node<MSG>) -> &TemplatedView<MSG> {
+    match node {
+        Node::Leaf(Leaf::TemplatedView(view)) => view,
+        _ => panic!("expecting a templated view"),
+    }
+}
+
+#[test]
+fn template_only_has_the_literal_parts() {
+    let node = Counter { count: 1 }.view();
+    let view = templated_view(&node);
+    assert_eq!(
+        (view.template)().render_to_string(),
+        r#"<div class="counter"><span class="label">count:</span><span><!----></span></div>"#
+    );
+    assert_eq!(
+        view.view.render_to_string(),
+        r#"<div class="counter"><span class="label">count:</span><span class="value-1">1</span></div>"#
+    );
+}
+
+#[test]
+fn template_is_the_same_for_every_view_of_a_call_site() {
+    let first = Counter { count: 1 }.view();
+    let second = Counter { count: 2 }.view();
+    let first = templated_view(&first);
+    let second = templated_view(&second);
+    assert_eq!(first.call_site, second.call_site);
+    assert_eq!((first.template)(), (second.template)());
+}
+
+#[test]
+fn template_of_mapped_view() {
+    let node = Counter { count: 1 }.view().map_msg(|()| 1);
+    let view = templated_view(&node);
+    assert_eq!(
+        (view.template)().render_to_string(),
+        r#"<div class="counter"><span class="label">count:</span><span><!----></span></div>"#
+    );
+}